@@ -33,6 +33,13 @@ import { watchCompilerOutput, Watcher } from "./utils/watch";
 
 const log = debug("hardhat:core:tasks:node");
 
+// 128 plus the number of the signal, which is what shells report when a
+// signal kills a process
+const SIGNAL_EXIT_CODES: Partial<Record<NodeJS.Signals, number>> = {
+  SIGINT: 130,
+  SIGTERM: 143,
+};
+
 function printDefaultConfigWarning() {
   console.log(
     picocolors.bold(
@@ -173,22 +180,51 @@ subtask(TASK_NODE_CREATE_SERVER)
   .addParam("hostname", undefined, undefined, types.string)
   .addParam("port", undefined, undefined, types.int)
   .addParam("provider", undefined, undefined, types.any)
+  .addOptionalParam("corsOrigins", undefined, undefined, types.any)
+  .addOptionalParam("pathPrefix", undefined, undefined, types.string)
   .setAction(
-    async ({
-      hostname,
-      port,
-      provider,
-    }: {
-      hostname: string;
-      port: number;
-      provider: EthereumProvider;
-    }): Promise<JsonRpcServer> => {
+    async (
+      {
+        hostname,
+        port,
+        provider,
+        corsOrigins: corsOriginsParam,
+        pathPrefix: pathPrefixParam,
+      }: {
+        hostname: string;
+        port: number;
+        provider: EthereumProvider;
+        corsOrigins?: string[];
+        pathPrefix?: string;
+      },
+      { config }
+    ): Promise<JsonRpcServer> => {
+      const serverUserConfig = config.networks[HARDHAT_NETWORK_NAME].server;
+
       const serverConfig: JsonRpcServerConfig = {
+        ...serverUserConfig,
+        corsOrigins: corsOriginsParam ?? serverUserConfig?.corsOrigins,
+        pathPrefix: pathPrefixParam ?? serverUserConfig?.pathPrefix,
         hostname,
         port,
         provider,
+        onShutdown: async () => {
+          // Don't keep mining blocks while the server is closing
+          await provider.request({
+            method: "evm_setIntervalMining",
+            params: [0],
+          });
+        },
       };
 
+      if (serverConfig.faultInjection !== undefined) {
+        console.warn(
+          picocolors.yellow(
+            "WARNING: Fault injection is enabled, so some requests will be delayed or fail on purpose. Only use it to test how clients deal with an unreliable node."
+          )
+        );
+      }
+
       const server = new JsonRpcServerImpl(serverConfig);
 
       return server;
@@ -228,6 +264,7 @@ subtask(TASK_NODE_SERVER_READY)
       {
         address,
         port,
+        server,
       }: {
         address: string;
         port: number;
@@ -236,9 +273,11 @@ subtask(TASK_NODE_SERVER_READY)
       },
       { config }
     ) => {
+      const pathPrefix = server.getPathPrefix?.() ?? "";
+
       console.log(
         picocolors.green(
-          `Started HTTP and WebSocket JSON-RPC server at http://${address}:${port}/`
+          `Started HTTP and WebSocket JSON-RPC server at http://${address}:${port}${pathPrefix}/`
         )
       );
 
@@ -274,6 +313,18 @@ task(TASK_NODE, "Starts a JSON-RPC server on top of Hardhat Network")
     undefined,
     types.int
   )
+  .addOptionalParam(
+    "corsOrigins",
+    "A comma-separated list of the origins allowed to make cross-origin requests (Defaults to every origin)",
+    undefined,
+    types.string
+  )
+  .addOptionalParam(
+    "pathPrefix",
+    "The path under which to serve JSON-RPC requests, for running behind a reverse proxy",
+    undefined,
+    types.string
+  )
   .setAction(
     async (
       {
@@ -281,11 +332,15 @@ task(TASK_NODE, "Starts a JSON-RPC server on top of Hardhat Network")
         fork: forkUrl,
         hostname: hostnameParam,
         port,
+        corsOrigins,
+        pathPrefix,
       }: {
         forkBlockNumber?: number;
         fork?: string;
         hostname?: string;
         port: number;
+        corsOrigins?: string;
+        pathPrefix?: string;
       },
       { config, hardhatArguments, network, run }
     ) => {
@@ -323,6 +378,11 @@ task(TASK_NODE, "Starts a JSON-RPC server on top of Hardhat Network")
           hostname,
           port,
           provider,
+          corsOrigins: corsOrigins
+            ?.split(",")
+            .map((origin) => origin.trim())
+            .filter((origin) => origin !== ""),
+          pathPrefix,
         });
 
         await run(TASK_NODE_SERVER_CREATED, {
@@ -361,7 +421,30 @@ task(TASK_NODE, "Starts a JSON-RPC server on top of Hardhat Network")
           server,
         });
 
+        // The first SIGINT or SIGTERM shuts the server down gracefully, and a
+        // second one kills the process as usual
+        const onTerminationSignal = (signal: NodeJS.Signals) => {
+          log(`Received ${signal}, shutting down`);
+
+          // Exit with the same code as if the signal had killed the process
+          process.exitCode = SIGNAL_EXIT_CODES[signal];
+
+          const shutdown =
+            server.shutdown !== undefined ? server.shutdown() : server.close();
+
+          shutdown.catch((error) => {
+            log("Failed to shut down the JSON-RPC server", error);
+          });
+        };
+
+        process.once("SIGINT", onTerminationSignal);
+        process.once("SIGTERM", onTerminationSignal);
+
         await server.waitUntilClosed();
+
+        process.removeListener("SIGINT", onTerminationSignal);
+        process.removeListener("SIGTERM", onTerminationSignal);
+
         await watcher?.close();
       } catch (error) {
         if (HardhatError.isHardhatError(error)) {
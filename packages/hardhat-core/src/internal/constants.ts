@@ -36,3 +36,7 @@ export const EDIT_DISTANCE_THRESHOLD = 3;
 export const HARDHAT_NETWORK_RESET_EVENT = "hardhatNetworkReset";
 export const HARDHAT_NETWORK_REVERT_SNAPSHOT_EVENT =
   "hardhatNetworkRevertSnapshot";
+export const HARDHAT_NETWORK_IMPERSONATE_ACCOUNT_EVENT =
+  "hardhatNetworkImpersonateAccount";
+export const HARDHAT_NETWORK_STOP_IMPERSONATING_ACCOUNT_EVENT =
+  "hardhatNetworkStopImpersonatingAccount";
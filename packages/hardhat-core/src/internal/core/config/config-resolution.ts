@@ -164,6 +164,16 @@ function resolveHardhatNetworkConfig(
       forking.blockNumber = hardhatNetworkConfig?.forking?.blockNumber;
     }
 
+    const blockHash = hardhatNetworkConfig.forking?.blockHash;
+    if (blockHash !== undefined) {
+      forking.blockHash = blockHash;
+    }
+
+    const blockTag = hardhatNetworkConfig.forking?.blockTag;
+    if (blockTag !== undefined) {
+      forking.blockTag = blockTag;
+    }
+
     const httpHeaders = hardhatNetworkConfig.forking?.httpHeaders;
     if (httpHeaders !== undefined) {
       forking.httpHeaders = httpHeaders;
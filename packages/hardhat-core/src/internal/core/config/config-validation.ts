@@ -64,6 +64,32 @@ function getErrorMessage(path: string, value: any, expectedType: string) {
   )} for ${path} - Expected a value of type ${expectedType}.`;
 }
 
+function validatePositiveInteger(
+  path: string,
+  value: unknown,
+  errors: string[]
+) {
+  if (typeof value === "number" && (!Number.isInteger(value) || value <= 0)) {
+    errors.push(getErrorMessage(path, value, "positive integer"));
+  }
+}
+
+function validateNonNegativeNumber(
+  path: string,
+  value: unknown,
+  errors: string[]
+) {
+  if (typeof value === "number" && !(value >= 0)) {
+    errors.push(getErrorMessage(path, value, "non-negative number"));
+  }
+}
+
+function validateProbability(path: string, value: unknown, errors: string[]) {
+  if (typeof value === "number" && !(value >= 0 && value <= 1)) {
+    errors.push(getErrorMessage(path, value, "number between 0 and 1"));
+  }
+}
+
 function getPrivateKeyError(index: number, network: string, message: string) {
   return `Invalid account: #${index} for network: ${network} - ${message}`;
 }
@@ -176,6 +202,27 @@ export const address = new t.Type<string>(
   t.identity
 );
 
+function isBlockHash(v: unknown): v is string {
+  if (typeof v !== "string") {
+    return false;
+  }
+
+  const trimmed = v.trim();
+
+  return (
+    trimmed.match(HEX_STRING_REGEX) !== null &&
+    trimmed.startsWith("0x") &&
+    trimmed.length === 66
+  );
+}
+
+export const blockHash = new t.Type<string>(
+  "block hash",
+  isBlockHash,
+  (u, c) => (isBlockHash(u) ? t.success(u) : t.failure(u, c)),
+  t.identity
+);
+
 export const decimalString = new t.Type<string>(
   "decimal string",
   isDecimalString,
@@ -222,6 +269,8 @@ const HardhatNetworkForkingConfig = t.type({
   enabled: optional(t.boolean),
   url: t.string,
   blockNumber: optional(t.number),
+  blockHash: optional(blockHash),
+  blockTag: optional(t.keyof({ safe: null, finalized: null })),
 });
 
 const HardhatNetworkMempoolConfig = t.type({
@@ -270,6 +319,52 @@ const HardhatNetworkChainConfig = t.type({
 
 const HardhatNetworkChainsConfig = t.record(Integer, HardhatNetworkChainConfig);
 
+const HardhatNetworkServerRateLimitConfig = t.type({
+  maxRequests: t.number,
+  windowMs: t.number,
+  trustProxy: optional(t.boolean),
+});
+
+const HardhatNetworkServerApiKeyConfig = t.type({
+  key: t.string,
+  namespaces: optional(t.array(t.string)),
+});
+
+const HardhatNetworkServerAuthConfig = t.type({
+  jwtSecret: optional(t.string),
+  apiKeys: optional(t.array(HardhatNetworkServerApiKeyConfig)),
+  publicNamespaces: optional(t.array(t.string)),
+});
+
+const HardhatNetworkServerMethodsConfig = t.type({
+  enabled: optional(t.array(t.string)),
+  disabled: optional(t.array(t.string)),
+});
+
+const HardhatNetworkServerFaultInjectionMethodConfig = t.type({
+  latencyMs: optional(t.number),
+  jitterMs: optional(t.number),
+  errorRate: optional(t.number),
+});
+
+const HardhatNetworkServerFaultInjectionConfig = t.type({
+  methods: optional(
+    t.record(t.string, HardhatNetworkServerFaultInjectionMethodConfig)
+  ),
+  dropSubscriptionEventRate: optional(t.number),
+});
+
+const HardhatNetworkServerConfig = t.type({
+  maxBatchSize: optional(t.number),
+  maxRequestBodySize: optional(t.number),
+  rateLimit: optional(HardhatNetworkServerRateLimitConfig),
+  auth: optional(HardhatNetworkServerAuthConfig),
+  methods: optional(HardhatNetworkServerMethodsConfig),
+  corsOrigins: optional(t.array(t.string)),
+  pathPrefix: optional(t.string),
+  faultInjection: optional(HardhatNetworkServerFaultInjectionConfig),
+});
+
 const commonNetworkConfigFields = {
   chainId: optional(t.number),
   from: optional(t.string),
@@ -295,10 +390,14 @@ const HardhatNetworkConfig = t.type({
   allowUnlimitedContractSize: optional(t.boolean),
   initialDate: optional(t.string),
   loggingEnabled: optional(t.boolean),
+  exposePrivateKeys: optional(t.boolean),
+  deterministicDeploymentProxy: optional(t.boolean),
+  genesisAlloc: optional(t.string),
   forking: optional(HardhatNetworkForkingConfig),
   mining: optional(HardhatNetworkMiningConfig),
   coinbase: optional(address),
   chains: optional(HardhatNetworkChainsConfig),
+  server: optional(HardhatNetworkServerConfig),
 });
 
 const HDAccountsConfig = t.type({
@@ -321,6 +420,7 @@ const HttpNetworkConfig = t.type({
   accounts: optional(NetworkConfigAccounts),
   httpHeaders: optional(HttpHeaders),
   timeout: optional(t.number),
+  allowUnprotectedTxs: optional(t.boolean),
 });
 
 const NetworkConfig = t.union([HardhatNetworkConfig, HttpNetworkConfig]);
@@ -499,6 +599,88 @@ export function getValidationErrors(config: any): string[] {
         });
       }
 
+      if (
+        hardhatNetwork.forking !== undefined &&
+        typeof hardhatNetwork.forking === "object"
+      ) {
+        const { blockNumber, blockHash: forkBlockHash, blockTag } =
+          hardhatNetwork.forking;
+        const forkBlockSelectors = [blockNumber, forkBlockHash, blockTag];
+
+        if (forkBlockSelectors.filter((s) => s !== undefined).length > 1) {
+          errors.push(
+            `HardhatConfig.networks.${HARDHAT_NETWORK_NAME}.forking can only have one of 'blockNumber', 'blockHash' or 'blockTag'`
+          );
+        }
+      }
+
+      if (
+        typeof hardhatNetwork.server === "object" &&
+        hardhatNetwork.server !== null
+      ) {
+        const {
+          maxBatchSize,
+          maxRequestBodySize,
+          rateLimit,
+          faultInjection,
+        } = hardhatNetwork.server;
+        const path = `HardhatConfig.networks.${HARDHAT_NETWORK_NAME}.server`;
+
+        validatePositiveInteger(`${path}.maxBatchSize`, maxBatchSize, errors);
+        validatePositiveInteger(
+          `${path}.maxRequestBodySize`,
+          maxRequestBodySize,
+          errors
+        );
+
+        if (typeof rateLimit === "object" && rateLimit !== null) {
+          validatePositiveInteger(
+            `${path}.rateLimit.maxRequests`,
+            rateLimit.maxRequests,
+            errors
+          );
+          validatePositiveInteger(
+            `${path}.rateLimit.windowMs`,
+            rateLimit.windowMs,
+            errors
+          );
+        }
+
+        if (typeof faultInjection === "object" && faultInjection !== null) {
+          const methods = faultInjection.methods ?? {};
+
+          for (const [method, methodConfig] of Object.entries<any>(methods)) {
+            if (typeof methodConfig !== "object" || methodConfig === null) {
+              continue;
+            }
+
+            const methodPath = `${path}.faultInjection.methods.${method}`;
+
+            validateNonNegativeNumber(
+              `${methodPath}.latencyMs`,
+              methodConfig.latencyMs,
+              errors
+            );
+            validateNonNegativeNumber(
+              `${methodPath}.jitterMs`,
+              methodConfig.jitterMs,
+              errors
+            );
+            validateProbability(
+              `${methodPath}.errorRate`,
+              methodConfig.errorRate,
+              errors
+            );
+          }
+
+          validateProbability(
+            `${path}.faultInjection.dropSubscriptionEventRate`,
+            faultInjection.dropSubscriptionEventRate,
+            errors
+          );
+        }
+      }
+
       if (hardhatNetwork.hardfork !== undefined) {
         if (
           !hardforkGte(hardhatNetwork.hardfork, HardforkName.CANCUN) &&
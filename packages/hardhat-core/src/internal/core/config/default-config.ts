@@ -167,6 +167,30 @@ export const defaultHardhatNetworkParams: Omit<
         hardforkHistory: new Map([[HardforkName.SHANGHAI, 0]]),
       },
     ],
+    [
+      8453, // base mainnet
+      {
+        hardforkHistory: new Map([[HardforkName.SHANGHAI, 0]]),
+      },
+    ],
+    [
+      84532, // base sepolia
+      {
+        hardforkHistory: new Map([[HardforkName.SHANGHAI, 0]]),
+      },
+    ],
+    [
+      137, // polygon mainnet
+      {
+        hardforkHistory: new Map([[HardforkName.SHANGHAI, 0]]),
+      },
+    ],
+    [
+      80002, // polygon amoy
+      {
+        hardforkHistory: new Map([[HardforkName.SHANGHAI, 0]]),
+      },
+    ],
   ]),
 };
 
@@ -486,6 +486,25 @@ Please check that you are sending an \`address\` parameter.`,
 Please check that you are sending a non-empty string for network or forking \`URL\` parameter.`,
       shouldBeReported: false,
     },
+    INVALID_GENESIS_ALLOC: {
+      number: 118,
+      message: "Invalid genesis alloc file %path%: %error%",
+      title: "Invalid genesis alloc file",
+      description: `The file set in the \`genesisAlloc\` field of your Hardhat Network config couldn't be read, or isn't a valid geth-style genesis file.
+
+Please check that the file exists and that its \`alloc\` field has the right format.`,
+      shouldBeReported: false,
+    },
+    INVALID_JWT_SECRET: {
+      number: 119,
+      message:
+        "Invalid JWT secret for the JSON-RPC server. It must be a 32-byte hex-encoded string.",
+      title: "Invalid JWT secret",
+      description: `The \`jwtSecret\` used to authenticate requests to the JSON-RPC server must be exactly 32 bytes, encoded as a hex string with an optional \`0x\` prefix.
+
+Please generate a valid secret, for example with \`openssl rand -hex 32\`.`,
+      shouldBeReported: false,
+    },
   },
   TASK_DEFINITIONS: {
     PARAM_AFTER_VARIADIC: {
@@ -35,7 +35,8 @@ export class LocalAccountsProvider extends ProviderWrapperWithChainId {
 
   constructor(
     provider: EIP1193Provider,
-    localAccountsHexPrivateKeys: string[]
+    localAccountsHexPrivateKeys: string[],
+    private readonly _allowUnprotectedTxs: boolean = false
   ) {
     super(provider);
 
@@ -253,7 +254,9 @@ export class LocalAccountsProvider extends ProviderWrapperWithChainId {
       "@nomicfoundation/ethereumjs-tx"
     );
 
-    const { Common } = await import("@nomicfoundation/ethereumjs-common");
+    const { Common, Hardfork } = await import(
+      "@nomicfoundation/ethereumjs-common"
+    );
 
     const txData = {
       ...transactionRequest,
@@ -290,6 +293,18 @@ export class LocalAccountsProvider extends ProviderWrapperWithChainId {
         },
         { common }
       );
+    } else if (this._allowUnprotectedTxs) {
+      // Legacy transactions are only signed with EIP-155 replay protection
+      // from Spurious Dragon onwards, so we sign them with an older hardfork
+      // to support chains that don't implement it.
+      const unprotectedCommon = Common.custom(
+        { chainId, networkId: chainId },
+        { hardfork: Hardfork.TangerineWhistle }
+      );
+
+      transaction = LegacyTransaction.fromTxData(txData, {
+        common: unprotectedCommon,
+      });
     } else {
       transaction = LegacyTransaction.fromTxData(txData, { common });
     }
@@ -307,7 +322,8 @@ export class HDWalletProvider extends LocalAccountsProvider {
     hdpath: string = "m/44'/60'/0'/0/",
     initialIndex: number = 0,
     count: number = 10,
-    passphrase: string = ""
+    passphrase: string = "",
+    allowUnprotectedTxs: boolean = false
   ) {
     // NOTE: If mnemonic has space or newline at the beginning or end, it will be trimmed.
     // This is because mnemonic containing them may generate different private keys.
@@ -324,7 +340,7 @@ export class HDWalletProvider extends LocalAccountsProvider {
       bytesToHex: bufferToHex,
     } = require("@nomicfoundation/ethereumjs-util");
     const privateKeysAsHex = privateKeys.map((pk) => bufferToHex(pk));
-    super(provider, privateKeysAsHex);
+    super(provider, privateKeysAsHex, allowUnprotectedTxs);
   }
 }
 
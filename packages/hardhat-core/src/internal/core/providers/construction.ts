@@ -11,15 +11,22 @@ import type {
   ProviderExtender,
 } from "../../../types";
 
+import path from "path";
+
 import type {
   ForkConfig,
+  GenesisAllocAccount,
   MempoolOrder,
 } from "../../hardhat-network/provider/node-types";
 import type * as DiskCacheT from "../../hardhat-network/provider/utils/disk-cache";
+import type * as GenesisAllocT from "../../hardhat-network/provider/utils/genesisAlloc";
 import { HARDHAT_NETWORK_NAME } from "../../constants";
 import { parseDateString } from "../../util/date";
 
-import { normalizeHardhatNetworkAccountsConfig } from "./util";
+import {
+  getHardhatNetworkAccountsDerivationPaths,
+  normalizeHardhatNetworkAccountsConfig,
+} from "./util";
 
 export function isHDAccountsConfig(
   accounts?: HttpNetworkAccountsUserConfig
@@ -71,6 +78,8 @@ export async function createProvider(
       forkConfig = {
         jsonRpcUrl: hardhatNetConfig.forking?.url,
         blockNumber: hardhatNetConfig.forking?.blockNumber,
+        blockHash: hardhatNetConfig.forking?.blockHash,
+        blockTag: hardhatNetConfig.forking?.blockTag,
         httpHeaders: hardhatNetConfig.forking.httpHeaders,
       };
     }
@@ -78,10 +87,25 @@ export async function createProvider(
     const accounts = normalizeHardhatNetworkAccountsConfig(
       hardhatNetConfig.accounts
     );
+    const derivationPaths = getHardhatNetworkAccountsDerivationPaths(
+      hardhatNetConfig.accounts
+    );
 
     const { getForkCacheDirPath } =
       require("../../hardhat-network/provider/utils/disk-cache") as typeof DiskCacheT;
 
+    let genesisAlloc: GenesisAllocAccount[] | undefined;
+    if (hardhatNetConfig.genesisAlloc !== undefined) {
+      const { readGenesisAllocFile } =
+        require("../../hardhat-network/provider/utils/genesisAlloc") as typeof GenesisAllocT;
+
+      genesisAlloc = readGenesisAllocFile(
+        paths !== undefined
+          ? path.resolve(paths.root, hardhatNetConfig.genesisAlloc)
+          : hardhatNetConfig.genesisAlloc
+      );
+    }
+
     eip1193Provider = await createHardhatNetworkProvider(
       {
         chainId: hardhatNetConfig.chainId,
@@ -98,7 +122,10 @@ export async function createProvider(
         mempoolOrder: hardhatNetConfig.mining.mempool.order as MempoolOrder,
         chains: hardhatNetConfig.chains,
         coinbase: hardhatNetConfig.coinbase,
-        genesisAccounts: accounts,
+        genesisAccounts: accounts.map((account, i) => ({
+          ...account,
+          derivationPath: derivationPaths?.[i],
+        })),
         allowUnlimitedContractSize: hardhatNetConfig.allowUnlimitedContractSize,
         allowBlocksWithSameTimestamp:
           hardhatNetConfig.allowBlocksWithSameTimestamp ?? false,
@@ -112,6 +139,10 @@ export async function createProvider(
         enableTransientStorage:
           hardhatNetConfig.enableTransientStorage ?? false,
         enableRip7212: hardhatNetConfig.enableRip7212 ?? false,
+        exposePrivateKeys: hardhatNetConfig.exposePrivateKeys ?? false,
+        deterministicDeploymentProxy:
+          hardhatNetConfig.deterministicDeploymentProxy ?? false,
+        genesisAlloc,
       },
       {
         enabled: hardhatNetConfig.loggingEnabled,
@@ -201,7 +232,11 @@ export function applyProviderWrappers(
     const accounts = netConfig.accounts;
 
     if (Array.isArray(accounts)) {
-      provider = new LocalAccountsProvider(provider, accounts);
+      provider = new LocalAccountsProvider(
+        provider,
+        accounts,
+        netConfig.allowUnprotectedTxs
+      );
     } else if (isHDAccountsConfig(accounts)) {
       provider = new HDWalletProvider(
         provider,
@@ -209,7 +244,8 @@ export function applyProviderWrappers(
         accounts.path,
         accounts.initialIndex,
         accounts.count,
-        accounts.passphrase
+        accounts.passphrase,
+        netConfig.allowUnprotectedTxs
       );
     }
 
@@ -12,7 +12,10 @@ import { CustomError } from "../errors";
 // -32603	Internal error	      Internal JSON-RPC error	            standard
 // -32004	Method not supported	Method is not implemented	          non-standard
 // -32000	Invalid input	        Missing or invalid parameters	      non-standard
+// -32001	Resource not found	  Requested resource not found	      non-standard
+// -32002	Resource unavailable	Requested resource not available	  non-standard
 // -32003	Transaction rejected	Transaction creation failed	        non-standard
+// -32005	Limit exceeded	      Request exceeds defined limit	      non-standard
 //
 //
 // Non standard:
@@ -21,10 +24,10 @@ import { CustomError } from "../errors";
 //                              response, but the result is not
 //                              in the expected format
 //
-// Not implemented:
+// Taken from EIP-1193: https://github.com/ethereum/EIPs/blob/master/EIPS/eip-1193.md#provider-errors
 //
-// -32001	Resource not found	  Requested resource not found	      non-standard
-// -32002	Resource unavailable	Requested resource not available	  non-standard
+// 4100   Unauthorized          The requested method has not been   standard
+//                              authorized
 
 export class ProviderError extends CustomError implements ProviderRpcError {
   public static isProviderError(other: any): other is ProviderError {
@@ -69,6 +72,18 @@ export class MethodNotFoundError extends ProviderError {
   }
 }
 
+export class MethodNotEnabledError extends ProviderError {
+  public static readonly CODE = MethodNotFoundError.CODE;
+
+  constructor(method: string, parent?: Error) {
+    super(
+      `Method ${method} is not enabled`,
+      MethodNotEnabledError.CODE,
+      parent
+    );
+  }
+}
+
 export class InvalidArgumentsError extends ProviderError {
   public static readonly CODE = -32602;
 
@@ -93,6 +108,22 @@ export class InvalidInputError extends ProviderError {
   }
 }
 
+export class ResourceNotFoundError extends ProviderError {
+  public static readonly CODE = -32001;
+
+  constructor(message: string, parent?: Error) {
+    super(message, ResourceNotFoundError.CODE, parent);
+  }
+}
+
+export class ResourceUnavailableError extends ProviderError {
+  public static readonly CODE = -32002;
+
+  constructor(message: string, parent?: Error) {
+    super(message, ResourceUnavailableError.CODE, parent);
+  }
+}
+
 export class TransactionExecutionError extends ProviderError {
   public static readonly CODE = -32003;
 
@@ -110,6 +141,20 @@ export class TransactionExecutionError extends ProviderError {
 export class MethodNotSupportedError extends ProviderError {
   public static readonly CODE = -32004;
 
+  /**
+   * Creates an error with an already formatted message, like the ones in the
+   * JSON-RPC errors returned by other nodes.
+   */
+  public static fromMessage(
+    message: string,
+    parent?: Error
+  ): MethodNotSupportedError {
+    const error = new MethodNotSupportedError("", parent);
+    error.message = message;
+
+    return error;
+  }
+
   constructor(method: string, parent?: Error) {
     super(
       `Method ${method} is not supported`,
@@ -119,6 +164,22 @@ export class MethodNotSupportedError extends ProviderError {
   }
 }
 
+export class LimitExceededError extends ProviderError {
+  public static readonly CODE = -32005;
+
+  constructor(message: string, parent?: Error) {
+    super(message, LimitExceededError.CODE, parent);
+  }
+}
+
+export class UnauthorizedError extends ProviderError {
+  public static readonly CODE = 4100;
+
+  constructor(message: string, parent?: Error) {
+    super(message, UnauthorizedError.CODE, parent);
+  }
+}
+
 export class InvalidResponseError extends ProviderError {
   public static readonly CODE = -32999;
 
@@ -126,3 +187,64 @@ export class InvalidResponseError extends ProviderError {
     super(message, InvalidResponseError.CODE, parent);
   }
 }
+
+/**
+ * Creates the `ProviderError` subclass that corresponds to the code of a
+ * JSON-RPC error object, falling back to a plain `ProviderError` for codes
+ * that don't have one. The error's `data` is preserved.
+ */
+export function providerErrorFromJsonRpcError(error: {
+  code: number;
+  message: string;
+  data?: unknown;
+}): ProviderError {
+  let providerError: ProviderError;
+
+  switch (error.code) {
+    case InvalidJsonInputError.CODE:
+      providerError = new InvalidJsonInputError(error.message);
+      break;
+    case InvalidRequestError.CODE:
+      providerError = new InvalidRequestError(error.message);
+      break;
+    case MethodNotFoundError.CODE:
+      providerError = new MethodNotFoundError(error.message);
+      break;
+    case InvalidArgumentsError.CODE:
+      providerError = new InvalidArgumentsError(error.message);
+      break;
+    case InternalError.CODE:
+      providerError = new InternalError(error.message);
+      break;
+    case MethodNotSupportedError.CODE:
+      providerError = MethodNotSupportedError.fromMessage(error.message);
+      break;
+    case InvalidInputError.CODE:
+      providerError = new InvalidInputError(error.message);
+      break;
+    case ResourceNotFoundError.CODE:
+      providerError = new ResourceNotFoundError(error.message);
+      break;
+    case ResourceUnavailableError.CODE:
+      providerError = new ResourceUnavailableError(error.message);
+      break;
+    case TransactionExecutionError.CODE:
+      providerError = new TransactionExecutionError(error.message);
+      break;
+    case LimitExceededError.CODE:
+      providerError = new LimitExceededError(error.message);
+      break;
+    case UnauthorizedError.CODE:
+      providerError = new UnauthorizedError(error.message);
+      break;
+    case InvalidResponseError.CODE:
+      providerError = new InvalidResponseError(error.message);
+      break;
+    default:
+      providerError = new ProviderError(error.message, error.code);
+  }
+
+  providerError.data = error.data;
+
+  return providerError;
+}
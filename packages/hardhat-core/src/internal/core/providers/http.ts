@@ -4,8 +4,10 @@ import { EventEmitter } from "events";
 
 import { EIP1193Provider, RequestArguments } from "../../../types";
 import {
+  HARDHAT_NETWORK_IMPERSONATE_ACCOUNT_EVENT,
   HARDHAT_NETWORK_RESET_EVENT,
   HARDHAT_NETWORK_REVERT_SNAPSHOT_EVENT,
+  HARDHAT_NETWORK_STOP_IMPERSONATING_ACCOUNT_EVENT,
 } from "../../constants";
 import {
   FailedJsonRpcResponse,
@@ -19,7 +21,7 @@ import { HardhatError } from "../errors";
 import { ERRORS } from "../errors-list";
 import { shouldUseProxy } from "../../util/proxy";
 
-import { ProviderError } from "./errors";
+import { ProviderError, providerErrorFromJsonRpcError } from "./errors";
 
 export function isErrorResponse(
   response: any
@@ -104,11 +106,7 @@ export class HttpProvider extends EventEmitter implements EIP1193Provider {
     const jsonRpcResponse = await this._fetchJsonRpcResponse(jsonRpcRequest);
 
     if (isErrorResponse(jsonRpcResponse)) {
-      const error = new ProviderError(
-        jsonRpcResponse.error.message,
-        jsonRpcResponse.error.code
-      );
-      error.data = jsonRpcResponse.error.data;
+      const error = providerErrorFromJsonRpcError(jsonRpcResponse.error);
       // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
       throw error;
     }
@@ -119,6 +117,18 @@ export class HttpProvider extends EventEmitter implements EIP1193Provider {
     if (args.method === "evm_revert") {
       this.emit(HARDHAT_NETWORK_REVERT_SNAPSHOT_EVENT);
     }
+    if (args.method === "hardhat_impersonateAccount") {
+      this.emit(
+        HARDHAT_NETWORK_IMPERSONATE_ACCOUNT_EVENT,
+        (args.params as any[])[0]
+      );
+    }
+    if (args.method === "hardhat_stopImpersonatingAccount") {
+      this.emit(
+        HARDHAT_NETWORK_STOP_IMPERSONATING_ACCOUNT_EVENT,
+        (args.params as any[])[0]
+      );
+    }
 
     return jsonRpcResponse.result;
   }
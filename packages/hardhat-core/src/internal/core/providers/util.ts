@@ -16,22 +16,8 @@ export function derivePrivateKeys(
   count: number,
   passphrase: string
 ): Buffer[] {
-  if (hdpath.match(HD_PATH_REGEX) === null) {
-    throw new HardhatError(ERRORS.NETWORK.INVALID_HD_PATH, { path: hdpath });
-  }
-
-  if (!hdpath.endsWith("/")) {
-    hdpath += "/";
-  }
-
-  const privateKeys: Buffer[] = [];
-
-  for (let i = initialIndex; i < initialIndex + count; i++) {
-    const privateKey = deriveKeyFromMnemonicAndPath(
-      mnemonic,
-      hdpath + i.toString(),
-      passphrase
-    );
+  return getDerivationPaths(hdpath, initialIndex, count).map((path) => {
+    const privateKey = deriveKeyFromMnemonicAndPath(mnemonic, path, passphrase);
 
     if (privateKey === undefined) {
       throw new HardhatError(ERRORS.NETWORK.CANT_DERIVE_KEY, {
@@ -40,10 +26,27 @@ export function derivePrivateKeys(
       });
     }
 
-    privateKeys.push(privateKey);
+    return privateKey;
+  });
+}
+
+/**
+ * Returns the derivation path of each account in an accounts config, in the
+ * same order as `normalizeHardhatNetworkAccountsConfig`, or undefined if the
+ * accounts aren't derived from a mnemonic.
+ */
+export function getHardhatNetworkAccountsDerivationPaths(
+  accountsConfig: HardhatNetworkAccountsConfig
+): string[] | undefined {
+  if (Array.isArray(accountsConfig)) {
+    return undefined;
   }
 
-  return privateKeys;
+  return getDerivationPaths(
+    accountsConfig.path,
+    accountsConfig.initialIndex,
+    accountsConfig.count
+  );
 }
 
 export function normalizeHardhatNetworkAccountsConfig(
@@ -66,3 +69,29 @@ export function normalizeHardhatNetworkAccountsConfig(
     balance: accountsConfig.accountsBalance ?? DEFAULT_HARDHAT_NETWORK_BALANCE,
   }));
 }
+
+/**
+ * Returns the paths of the `count` accounts derived from `hdpath`, starting
+ * at `initialIndex`.
+ */
+function getDerivationPaths(
+  hdpath: string,
+  initialIndex: number,
+  count: number
+): string[] {
+  if (hdpath.match(HD_PATH_REGEX) === null) {
+    throw new HardhatError(ERRORS.NETWORK.INVALID_HD_PATH, { path: hdpath });
+  }
+
+  if (!hdpath.endsWith("/")) {
+    hdpath += "/";
+  }
+
+  const paths: string[] = [];
+
+  for (let i = initialIndex; i < initialIndex + count; i++) {
+    paths.push(hdpath + i.toString());
+  }
+
+  return paths;
+}
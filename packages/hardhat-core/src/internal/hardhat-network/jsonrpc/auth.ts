@@ -0,0 +1,153 @@
+import { createHmac, timingSafeEqual } from "crypto";
+
+import { HardhatError } from "../../core/errors";
+import { ERRORS } from "../../core/errors-list";
+import { getMethodNamespace } from "../../util/jsonrpc";
+
+// Same tolerance as the Engine API's JWT authentication
+const JWT_IAT_TOLERANCE_SECONDS = 60;
+
+const JWT_SECRET_REGEX = /^(0x)?[0-9a-fA-F]{64}$/;
+
+export interface JsonRpcApiKeyConfig {
+  key: string;
+
+  /**
+   * The namespaces (e.g. `eth`, `hardhat`, `debug`) that this key can use.
+   * If undefined, the key can use every method.
+   */
+  namespaces?: string[];
+}
+
+export interface JsonRpcAuthConfig {
+  /**
+   * A 32-byte hex-encoded secret used to verify HS256 JWTs sent as bearer
+   * tokens, like the Engine API does. A valid JWT can use every method.
+   */
+  jwtSecret?: string;
+
+  /**
+   * Static API keys sent as bearer tokens.
+   */
+  apiKeys?: JsonRpcApiKeyConfig[];
+
+  /**
+   * The namespaces that can be used without sending any credentials.
+   */
+  publicNamespaces?: string[];
+}
+
+/**
+ * The namespaces a client is allowed to use, or `"all"`.
+ */
+export type JsonRpcPermissions = "all" | Set<string>;
+
+export class JsonRpcAuthenticator {
+  private readonly _jwtSecret?: Buffer;
+
+  constructor(private readonly _config: JsonRpcAuthConfig) {
+    if (_config.jwtSecret !== undefined) {
+      // Buffer.from ignores invalid hex, so a bad secret would end up as an
+      // empty or truncated HMAC key
+      if (!JWT_SECRET_REGEX.test(_config.jwtSecret)) {
+        throw new HardhatError(ERRORS.NETWORK.INVALID_JWT_SECRET);
+      }
+
+      const secret = _config.jwtSecret.startsWith("0x")
+        ? _config.jwtSecret.slice(2)
+        : _config.jwtSecret;
+
+      this._jwtSecret = Buffer.from(secret, "hex");
+    }
+  }
+
+  /**
+   * Returns the permissions granted by the value of an `Authorization`
+   * header. Missing or invalid credentials only get the public namespaces.
+   */
+  public getPermissions(
+    authorizationHeader: string | undefined,
+    now = Date.now()
+  ): JsonRpcPermissions {
+    const publicPermissions = new Set(this._config.publicNamespaces ?? []);
+
+    if (authorizationHeader === undefined) {
+      return publicPermissions;
+    }
+
+    const [scheme, token] = authorizationHeader.trim().split(/\s+/);
+    if (scheme?.toLowerCase() !== "bearer" || token === undefined) {
+      return publicPermissions;
+    }
+
+    for (const apiKey of this._config.apiKeys ?? []) {
+      if (_safeEqual(apiKey.key, token)) {
+        return apiKey.namespaces === undefined
+          ? "all"
+          : new Set([...publicPermissions, ...apiKey.namespaces]);
+      }
+    }
+
+    if (
+      this._jwtSecret !== undefined &&
+      _isValidJwt(token, this._jwtSecret, now)
+    ) {
+      return "all";
+    }
+
+    return publicPermissions;
+  }
+}
+
+export function isMethodAllowed(
+  method: string,
+  permissions: JsonRpcPermissions
+): boolean {
+  return permissions === "all" || permissions.has(getMethodNamespace(method));
+}
+
+function _safeEqual(a: string, b: string): boolean {
+  const aBuffer = Buffer.from(a);
+  const bBuffer = Buffer.from(b);
+
+  return aBuffer.length === bBuffer.length && timingSafeEqual(aBuffer, bBuffer);
+}
+
+function _isValidJwt(token: string, secret: Buffer, now: number): boolean {
+  const parts = token.split(".");
+  if (parts.length !== 3) {
+    return false;
+  }
+
+  const [encodedHeader, encodedPayload, encodedSignature] = parts;
+
+  let header: any;
+  let payload: any;
+  try {
+    header = JSON.parse(Buffer.from(encodedHeader, "base64url").toString());
+    payload = JSON.parse(Buffer.from(encodedPayload, "base64url").toString());
+  } catch {
+    return false;
+  }
+
+  if (header?.alg !== "HS256") {
+    return false;
+  }
+
+  const expectedSignature = createHmac("sha256", secret)
+    .update(`${encodedHeader}.${encodedPayload}`)
+    .digest();
+  const signature = Buffer.from(encodedSignature, "base64url");
+
+  if (
+    signature.length !== expectedSignature.length ||
+    !timingSafeEqual(signature, expectedSignature)
+  ) {
+    return false;
+  }
+
+  return (
+    typeof payload?.iat === "number" &&
+    Math.abs(now / 1000 - payload.iat) <= JWT_IAT_TOLERANCE_SECONDS
+  );
+}
@@ -0,0 +1,92 @@
+import { InternalError } from "../../core/providers/errors";
+
+export interface FaultInjectionMethodConfig {
+  /**
+   * The latency added to every request, in milliseconds.
+   */
+  latencyMs?: number;
+
+  /**
+   * A random latency between 0 and this value is added on top of
+   * `latencyMs`, in milliseconds.
+   */
+  jitterMs?: number;
+
+  /**
+   * The probability, between 0 and 1, of a request failing with an internal
+   * error instead of being handled.
+   */
+  errorRate?: number;
+}
+
+export interface FaultInjectionConfig {
+  /**
+   * Faults to inject per method. The `"*"` entry applies to every method
+   * without its own entry.
+   */
+  methods?: { [method: string]: FaultInjectionMethodConfig };
+
+  /**
+   * The probability, between 0 and 1, of a subscription notification not
+   * being sent.
+   */
+  dropSubscriptionEventRate?: number;
+}
+
+/**
+ * Simulates an unreliable node, so that clients can test how they handle
+ * slow or failing requests.
+ */
+export class FaultInjector {
+  constructor(
+    private readonly _config: FaultInjectionConfig,
+    private readonly _random: () => number = Math.random
+  ) {}
+
+  /**
+   * Waits for the latency configured for `method`, and throws if the request
+   * should fail.
+   */
+  public async beforeRequest(method: string): Promise<void> {
+    const methodConfig = this._getMethodConfig(method);
+    if (methodConfig === undefined) {
+      return;
+    }
+
+    const latency =
+      (methodConfig.latencyMs ?? 0) +
+      this._random() * (methodConfig.jitterMs ?? 0);
+
+    if (latency > 0) {
+      await new Promise((resolve) => setTimeout(resolve, latency));
+    }
+
+    if (
+      methodConfig.errorRate !== undefined &&
+      this._random() < methodConfig.errorRate
+    ) {
+      throw new InternalError(`Injected failure in ${method}`);
+    }
+  }
+
+  public shouldDropSubscriptionEvent(): boolean {
+    const { dropSubscriptionEventRate } = this._config;
+
+    return (
+      dropSubscriptionEventRate !== undefined &&
+      this._random() < dropSubscriptionEventRate
+    );
+  }
+
+  private _getMethodConfig(
+    method: string
+  ): FaultInjectionMethodConfig | undefined {
+    const methods = this._config.methods ?? {};
+
+    if (Object.prototype.hasOwnProperty.call(methods, method)) {
+      return methods[method];
+    }
+
+    return methods["*"];
+  }
+}
@@ -8,7 +8,11 @@ import {
   InternalError,
   InvalidJsonInputError,
   InvalidRequestError,
+  LimitExceededError,
+  MethodNotEnabledError,
   ProviderError,
+  ResourceUnavailableError,
+  UnauthorizedError,
 } from "../../core/providers/errors";
 import {
   FailedJsonRpcResponse,
@@ -19,46 +23,187 @@ import {
   JsonRpcResponse,
 } from "../../util/jsonrpc";
 
+import {
+  JsonRpcAuthConfig,
+  JsonRpcAuthenticator,
+  JsonRpcPermissions,
+  isMethodAllowed,
+} from "./auth";
+import { FaultInjectionConfig, FaultInjector } from "./fault-injection";
+import { JsonRpcMethodsConfig, isMethodEnabled } from "./method-filter";
+import { RateLimitConfig, RateLimiter } from "./rate-limiter";
+
 /* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
 
+// The long-polling methods and the result they return when the server shuts
+// down, so that it doesn't wait for them to time out
+const LONG_POLLING_METHODS_SHUTDOWN_RESULTS = new Map<string, unknown>([
+  ["hardhat_waitForFilterChanges", []],
+]);
+
+export interface JsonRpcHandlerConfig {
+  /**
+   * The maximum number of requests in a single batch.
+   */
+  maxBatchSize?: number;
+
+  /**
+   * The maximum size of an HTTP request body, in bytes.
+   */
+  maxRequestBodySize?: number;
+
+  /**
+   * Limits the number of HTTP requests per client address, and of messages
+   * per websocket connection. A batch counts as a single request.
+   */
+  rateLimit?: RateLimitConfig;
+
+  /**
+   * Requires clients to authenticate with an API key or a JWT. If undefined,
+   * every client can use every method.
+   */
+  auth?: JsonRpcAuthConfig;
+
+  /**
+   * Enables or disables namespaces and methods for every client.
+   */
+  methods?: JsonRpcMethodsConfig;
+
+  /**
+   * The origins allowed to make cross-origin requests. If undefined, every
+   * origin is allowed.
+   */
+  corsOrigins?: string[];
+
+  /**
+   * Serves JSON-RPC and the health endpoints under this path (e.g. `/rpc`),
+   * for when the server runs behind a reverse proxy. Requests to other paths
+   * get a 404 response.
+   */
+  pathPrefix?: string;
+
+  /**
+   * Adds latency and errors to requests, and drops subscription
+   * notifications, to test how clients deal with an unreliable node. Only
+   * meant for testing.
+   */
+  faultInjection?: FaultInjectionConfig;
+}
+
 export class JsonRpcHandler {
-  constructor(private readonly _provider: EIP1193Provider) {}
+  private readonly _httpRateLimiter?: RateLimiter<string>;
+  private readonly _wsRateLimiter?: RateLimiter<WebSocket>;
+  private readonly _authenticator?: JsonRpcAuthenticator;
+  private readonly _faultInjector?: FaultInjector;
+  private readonly _pathPrefix: string;
+
+  private _isShuttingDown = false;
+  private _inFlightRequests = 0;
+  private _drainedListeners: Array<() => void> = [];
+  private _shutdownListeners: Array<() => void> = [];
+
+  constructor(
+    private readonly _provider: EIP1193Provider,
+    private readonly _config: JsonRpcHandlerConfig = {}
+  ) {
+    if (_config.rateLimit !== undefined) {
+      this._httpRateLimiter = new RateLimiter(_config.rateLimit);
+      this._wsRateLimiter = new RateLimiter(_config.rateLimit);
+    }
+
+    if (_config.auth !== undefined) {
+      this._authenticator = new JsonRpcAuthenticator(_config.auth);
+    }
+
+    if (_config.faultInjection !== undefined) {
+      this._faultInjector = new FaultInjector(_config.faultInjection);
+    }
+
+    this._pathPrefix = _normalizePathPrefix(_config.pathPrefix);
+  }
+
+  /**
+   * Makes the handler reject every new request. The returned promise
+   * resolves once the requests that were already being handled complete.
+   */
+  public async stopAcceptingRequests(): Promise<void> {
+    this._isShuttingDown = true;
+
+    const shutdownListeners = this._shutdownListeners;
+    this._shutdownListeners = [];
+    shutdownListeners.forEach((listener) => listener());
+
+    if (this._inFlightRequests === 0) {
+      return;
+    }
+
+    await new Promise<void>((resolve) => {
+      this._drainedListeners.push(resolve);
+    });
+  }
+
+  /**
+   * Returns the normalized path prefix, which is either empty or starts with
+   * a slash and doesn't end with one.
+   */
+  public getPathPrefix(): string {
+    return this._pathPrefix;
+  }
+
+  /**
+   * Returns true if a request to `url` should be handled by this handler.
+   */
+  public isPathAllowed(url: string | undefined): boolean {
+    return this._getRelativePath(url) !== undefined;
+  }
 
   public handleHttp = async (req: IncomingMessage, res: ServerResponse) => {
-    this._setCorsHeaders(res);
+    this._setCorsHeaders(req, res);
     if (req.method === "OPTIONS") {
       this._sendEmptyResponse(res);
       return;
     }
 
-    let jsonHttpRequest: any;
-    try {
-      jsonHttpRequest = await _readJsonHttpRequest(req);
-    } catch (error) {
-      this._sendResponse(res, _handleError(error));
+    const path = this._getRelativePath(req.url);
+    if (path === undefined) {
+      this._sendStatusResponse(res, 404, { status: "not found" });
       return;
     }
 
-    if (Array.isArray(jsonHttpRequest)) {
-      const responses = await Promise.all(
-        jsonHttpRequest.map((singleReq: any) =>
-          this._handleSingleRequest(singleReq)
-        )
-      );
+    if (req.method === "GET" && path === "/health") {
+      this._sendStatusResponse(res, 200, { status: "ok" });
+      return;
+    }
 
-      this._sendResponse(res, responses);
+    if (req.method === "GET" && path === "/ready") {
+      await this._handleReadinessCheck(res);
       return;
     }
 
-    const rpcResp = await this._handleSingleRequest(jsonHttpRequest);
+    if (this._isShuttingDown) {
+      this._sendResponse(res, _handleError(_shuttingDownError()));
+      return;
+    }
 
-    this._sendResponse(res, rpcResp);
+    const clientAddress = this._getClientAddress(req);
+    if (this._httpRateLimiter?.tryAcquire(clientAddress) === false) {
+      this._sendResponse(res, _handleError(_rateLimitExceededError()));
+      return;
+    }
+
+    await this._trackInFlightRequest(() =>
+      this._handleJsonRpcHttpRequest(req, res)
+    );
   };
 
-  public handleWs = async (ws: WebSocket) => {
+  public handleWs = async (ws: WebSocket, request?: IncomingMessage) => {
     const subscriptions: string[] = [];
     let isClosed = false;
 
+    const permissions = this._authenticator?.getPermissions(
+      request?.headers.authorization
+    );
+
     const listener = (payload: { subscription: string; result: any }) => {
       // Don't attempt to send a message to the websocket if we already know it is closed,
       // or the current websocket connection isn't interested in the particular subscription.
@@ -66,6 +211,10 @@ export class JsonRpcHandler {
         return;
       }
 
+      if (this._faultInjector?.shouldDropSubscriptionEvent() === true) {
+        return;
+      }
+
       try {
         ws.send(
           JSON.stringify({
@@ -87,15 +236,37 @@ export class JsonRpcHandler {
       let rpcResp: JsonRpcResponse | JsonRpcResponse[];
 
       try {
+        if (this._isShuttingDown) {
+          throw _shuttingDownError();
+        }
+
+        if (this._wsRateLimiter?.tryAcquire(ws) === false) {
+          throw _rateLimitExceededError();
+        }
+
         rpcReq = _readWsRequest(msg as string);
 
-        rpcResp = Array.isArray(rpcReq)
-          ? await Promise.all(
-              rpcReq.map((req) =>
-                this._handleSingleWsRequest(req, subscriptions)
+        if (Array.isArray(rpcReq)) {
+          const batchSizeError = this._validateBatchSize(rpcReq);
+          if (batchSizeError !== undefined) {
+            throw batchSizeError;
+          }
+        }
+
+        const batchOrSingle = rpcReq;
+        rpcResp = await this._trackInFlightRequest(() =>
+          Array.isArray(batchOrSingle)
+            ? Promise.all(
+                batchOrSingle.map((req) =>
+                  this._handleSingleWsRequest(req, subscriptions, permissions)
+                )
+              )
+            : this._handleSingleWsRequest(
+                batchOrSingle,
+                subscriptions,
+                permissions
               )
-            )
-          : await this._handleSingleWsRequest(rpcReq, subscriptions);
+        );
       } catch (error) {
         rpcResp = _handleError(error);
       }
@@ -107,6 +278,8 @@ export class JsonRpcHandler {
       // Remove eth_subscribe listener.
       this._provider.removeListener("notification", listener);
 
+      this._wsRateLimiter?.delete(ws);
+
       // Clear any active subscriptions for the closed websocket connection.
       isClosed = true;
       subscriptions.forEach(async (subscriptionId) => {
@@ -118,18 +291,193 @@ export class JsonRpcHandler {
     });
   };
 
+  private async _handleJsonRpcHttpRequest(
+    req: IncomingMessage,
+    res: ServerResponse
+  ) {
+    let jsonHttpRequest: any;
+    try {
+      jsonHttpRequest = await _readJsonHttpRequest(
+        req,
+        this._config.maxRequestBodySize
+      );
+    } catch (error) {
+      this._sendResponse(res, _handleError(error));
+      return;
+    }
+
+    const permissions = this._authenticator?.getPermissions(
+      req.headers.authorization
+    );
+
+    if (Array.isArray(jsonHttpRequest)) {
+      const batchSizeError = this._validateBatchSize(jsonHttpRequest);
+      if (batchSizeError !== undefined) {
+        this._sendResponse(res, _handleError(batchSizeError));
+        return;
+      }
+
+      const responses = await Promise.all(
+        jsonHttpRequest.map((singleReq: any) =>
+          this._handleSingleRequest(singleReq, permissions)
+        )
+      );
+
+      this._sendResponse(res, responses);
+      return;
+    }
+
+    const rpcResp = await this._handleSingleRequest(
+      jsonHttpRequest,
+      permissions
+    );
+
+    this._sendResponse(res, rpcResp);
+  }
+
+  private async _trackInFlightRequest<T>(handle: () => Promise<T>): Promise<T> {
+    this._inFlightRequests += 1;
+
+    try {
+      return await handle();
+    } finally {
+      this._inFlightRequests -= 1;
+
+      if (this._inFlightRequests === 0) {
+        const listeners = this._drainedListeners;
+        this._drainedListeners = [];
+        listeners.forEach((listener) => listener());
+      }
+    }
+  }
+
+  /**
+   * Returns the result of `promise`, or `shutdownResult` if the server starts
+   * shutting down first.
+   */
+  private async _untilShutdown<T>(
+    promise: Promise<T>,
+    shutdownResult: T
+  ): Promise<T> {
+    let listener: () => void = () => {};
+    const shutdown = new Promise<T>((resolve) => {
+      listener = () => resolve(shutdownResult);
+    });
+
+    this._shutdownListeners.push(listener);
+
+    try {
+      return await Promise.race([promise, shutdown]);
+    } finally {
+      this._shutdownListeners = this._shutdownListeners.filter(
+        (l) => l !== listener
+      );
+    }
+  }
+
+  private _validateBatchSize(batch: unknown[]): LimitExceededError | undefined {
+    const { maxBatchSize } = this._config;
+    if (maxBatchSize !== undefined && batch.length > maxBatchSize) {
+      return new LimitExceededError(
+        `Batch of ${batch.length} requests exceeds the limit of ${maxBatchSize} requests`
+      );
+    }
+  }
+
   private _sendEmptyResponse(res: ServerResponse) {
     res.writeHead(200);
     res.end();
   }
 
-  private _setCorsHeaders(res: ServerResponse) {
-    res.setHeader("Access-Control-Allow-Origin", "*");
+  private _setCorsHeaders(req: IncomingMessage, res: ServerResponse) {
+    const { corsOrigins } = this._config;
+    if (corsOrigins === undefined) {
+      res.setHeader("Access-Control-Allow-Origin", "*");
+    } else {
+      res.setHeader("Vary", "Origin");
+
+      const origin = req.headers.origin;
+      if (origin !== undefined && corsOrigins.includes(origin)) {
+        res.setHeader("Access-Control-Allow-Origin", origin);
+      }
+    }
+
     res.setHeader("Access-Control-Request-Method", "*");
     res.setHeader("Access-Control-Allow-Methods", "OPTIONS, GET");
     res.setHeader("Access-Control-Allow-Headers", "*");
   }
 
+  private _sendStatusResponse(
+    res: ServerResponse,
+    statusCode: number,
+    body: { status: string }
+  ) {
+    res.statusCode = statusCode;
+    res.setHeader("Content-Type", "application/json");
+    res.end(JSON.stringify(body));
+  }
+
+  /**
+   * The server is ready once the provider can answer requests, which for a
+   * forked network means that it could reach the remote node.
+   */
+  private async _handleReadinessCheck(res: ServerResponse) {
+    if (this._isShuttingDown) {
+      this._sendStatusResponse(res, 503, { status: "shutting down" });
+      return;
+    }
+
+    try {
+      await this._provider.request({ method: "eth_chainId" });
+    } catch {
+      this._sendStatusResponse(res, 503, { status: "unavailable" });
+      return;
+    }
+
+    this._sendStatusResponse(res, 200, { status: "ready" });
+  }
+
+  /**
+   * Returns the path of `url` relative to the path prefix, or undefined if
+   * it's outside of it. Without a path prefix, every path is allowed.
+   */
+  private _getRelativePath(url: string | undefined): string | undefined {
+    const path = (url ?? "/").split("?")[0];
+
+    if (this._pathPrefix === "") {
+      return path;
+    }
+
+    if (path === this._pathPrefix) {
+      return "/";
+    }
+
+    if (path.startsWith(`${this._pathPrefix}/`)) {
+      return path.slice(this._pathPrefix.length);
+    }
+
+    return undefined;
+  }
+
+  /**
+   * Returns the address that HTTP requests are rate limited by. Behind a
+   * trusted proxy, that's the last address of `X-Forwarded-For`, which is
+   * the one the proxy added.
+   */
+  private _getClientAddress(req: IncomingMessage): string {
+    const forwardedFor = req.headers["x-forwarded-for"];
+
+    if (
+      this._config.rateLimit?.trustProxy === true &&
+      typeof forwardedFor === "string"
+    ) {
+      const addresses = forwardedFor.split(",");
+      return addresses[addresses.length - 1].trim();
+    }
+
+    return req.socket.remoteAddress ?? "";
+  }
+
   private _sendResponse(
     res: ServerResponse,
     rpcResp: JsonRpcResponse | JsonRpcResponse[]
@@ -140,7 +488,8 @@ export class JsonRpcHandler {
   }
 
   private async _handleSingleRequest(
-    req: JsonRpcRequest
+    req: JsonRpcRequest,
+    permissions?: JsonRpcPermissions
   ): Promise<JsonRpcResponse> {
     if (!isValidJsonRequest(req)) {
       return _handleError(new InvalidRequestError("Invalid request"));
@@ -150,7 +499,7 @@ export class JsonRpcHandler {
     let rpcResp: JsonRpcResponse | undefined;
 
     try {
-      rpcResp = await this._handleRequest(rpcReq);
+      rpcResp = await this._handleRequest(rpcReq, permissions);
     } catch (error) {
       rpcResp = _handleError(error);
     }
@@ -170,9 +519,10 @@ export class JsonRpcHandler {
 
   private async _handleSingleWsRequest(
     rpcReq: JsonRpcRequest,
-    subscriptions: string[]
+    subscriptions: string[],
+    permissions?: JsonRpcPermissions
   ) {
-    const rpcResp = await this._handleSingleRequest(rpcReq);
+    const rpcResp = await this._handleSingleRequest(rpcReq, permissions);
 
     // If eth_subscribe was successful, keep track of the subscription id,
     // so we can cleanup on websocket close.
@@ -187,13 +537,37 @@ export class JsonRpcHandler {
   }
 
   private _handleRequest = async (
-    req: JsonRpcRequest
+    req: JsonRpcRequest,
+    permissions?: JsonRpcPermissions
   ): Promise<JsonRpcResponse> => {
-    const result = await this._provider.request({
+    if (
+      this._config.methods !== undefined &&
+      !isMethodEnabled(req.method, this._config.methods)
+    ) {
+      throw new MethodNotEnabledError(req.method);
+    }
+
+    if (
+      permissions !== undefined &&
+      !isMethodAllowed(req.method, permissions)
+    ) {
+      throw new UnauthorizedError(`Unauthorized to call ${req.method}`);
+    }
+
+    await this._faultInjector?.beforeRequest(req.method);
+
+    const resultPromise = this._provider.request({
       method: req.method,
       params: req.params,
     });
 
+    const result = LONG_POLLING_METHODS_SHUTDOWN_RESULTS.has(req.method)
+      ? await this._untilShutdown(
+          resultPromise,
+          LONG_POLLING_METHODS_SHUTDOWN_RESULTS.get(req.method)
+        )
+      : await resultPromise;
+
     return {
       jsonrpc: "2.0",
       id: req.id,
@@ -202,15 +576,24 @@ export class JsonRpcHandler {
   };
 }
 
-const _readJsonHttpRequest = async (req: IncomingMessage): Promise<any> => {
+const _readJsonHttpRequest = async (
+  req: IncomingMessage,
+  maxRequestBodySize?: number
+): Promise<any> => {
   let json;
 
   try {
-    const buf = await getRawBody(req);
+    const buf = await getRawBody(req, { limit: maxRequestBodySize });
     const text = buf.toString();
 
     json = JSON.parse(text);
   } catch (error) {
+    if ((error as any)?.type === "entity.too.large") {
+      throw new LimitExceededError(
+        `Request body exceeds the limit of ${maxRequestBodySize} bytes`
+      );
+    }
+
     if (error instanceof Error) {
       throw new InvalidJsonInputError(`Parse error: ${error.message}`);
     }
@@ -238,6 +621,23 @@ const _readWsRequest = (msg: string): JsonRpcRequest | JsonRpcRequest[] => {
   return json;
 };
 
+const _normalizePathPrefix = (pathPrefix: string | undefined): string => {
+  if (pathPrefix === undefined) {
+    return "";
+  }
+
+  const trimmed = pathPrefix.replace(/^\/+|\/+$/g, "");
+  return trimmed === "" ? "" : `/${trimmed}`;
+};
+
+const _shuttingDownError = (): ResourceUnavailableError => {
+  return new ResourceUnavailableError("The server is shutting down");
+};
+
+const _rateLimitExceededError = (): LimitExceededError => {
+  return new LimitExceededError("Too many requests, please try again later");
+};
+
 const _handleError = (error: any): JsonRpcResponse => {
   // extract the relevant fields from the error before wrapping it
   let txHash: string | undefined;
@@ -0,0 +1,40 @@
+import { getMethodNamespace } from "../../util/jsonrpc";
+
+/**
+ * Each entry can be a namespace (e.g. `hardhat`) or a method name (e.g.
+ * `evm_mine`). Method entries take precedence over namespace entries, so
+ * `{ disabled: ["hardhat"], enabled: ["hardhat_mine"] }` only allows
+ * `hardhat_mine` from the `hardhat` namespace.
+ */
+export interface JsonRpcMethodsConfig {
+  /**
+   * If defined, only these namespaces and methods can be used.
+   */
+  enabled?: string[];
+
+  disabled?: string[];
+}
+
+export function isMethodEnabled(
+  method: string,
+  config: JsonRpcMethodsConfig
+): boolean {
+  const enabled = config.enabled;
+  const disabled = config.disabled ?? [];
+
+  if (disabled.includes(method)) {
+    return false;
+  }
+
+  if (enabled?.includes(method) === true) {
+    return true;
+  }
+
+  const namespace = getMethodNamespace(method);
+
+  if (disabled.includes(namespace)) {
+    return false;
+  }
+
+  return enabled === undefined || enabled.includes(namespace);
+}
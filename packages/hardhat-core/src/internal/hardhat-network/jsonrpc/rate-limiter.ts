@@ -0,0 +1,72 @@
+export interface RateLimitConfig {
+  maxRequests: number;
+  windowMs: number;
+
+  /**
+   * Limits HTTP requests by the client address that a reverse proxy added to
+   * the `X-Forwarded-For` header, instead of by the address of the socket,
+   * which would be the proxy's for every client. Only enable it behind a
+   * proxy that sets the header, as clients can send their own.
+   */
+  trustProxy?: boolean;
+}
+
+interface RateLimitWindow {
+  start: number;
+  count: number;
+}
+
+/**
+ * A fixed-window rate limiter. Each key (e.g. a client address or a
+ * websocket connection) can make up to `maxRequests` requests every
+ * `windowMs` milliseconds.
+ */
+export class RateLimiter<KeyT> {
+  private readonly _windows = new Map<KeyT, RateLimitWindow>();
+  private _lastPrune = 0;
+
+  constructor(private readonly _config: RateLimitConfig) {}
+
+  /**
+   * Records a request for `key`, returning `false` if it exceeds the limit.
+   */
+  public tryAcquire(key: KeyT, now = Date.now()): boolean {
+    this._pruneExpiredWindows(now);
+
+    const window = this._windows.get(key);
+
+    if (window === undefined || now - window.start >= this._config.windowMs) {
+      this._windows.set(key, { start: now, count: 1 });
+      return true;
+    }
+
+    if (window.count >= this._config.maxRequests) {
+      return false;
+    }
+
+    window.count += 1;
+    return true;
+  }
+
+  public delete(key: KeyT) {
+    this._windows.delete(key);
+  }
+
+  /**
+   * Removes the windows that already expired, at most once per window, so
+   * that keys that stop sending requests don't stay in memory forever.
+   */
+  private _pruneExpiredWindows(now: number) {
+    if (now - this._lastPrune < this._config.windowMs) {
+      return;
+    }
+
+    this._lastPrune = now;
+
+    for (const [key, window] of this._windows) {
+      if (now - window.start >= this._config.windowMs) {
+        this._windows.delete(key);
+      }
+    }
+  }
+}
@@ -11,33 +11,48 @@ import {
 } from "../../../types";
 import { HttpProvider } from "../../core/providers/http";
 
-import { JsonRpcHandler } from "./handler";
+import { JsonRpcHandler, JsonRpcHandlerConfig } from "./handler";
 
 const log = debug("hardhat:core:hardhat-network:jsonrpc");
 
-export interface JsonRpcServerConfig {
+export interface JsonRpcServerConfig extends JsonRpcHandlerConfig {
   hostname: string;
   port: number;
 
   provider: EIP1193Provider;
+
+  /**
+   * Called by `shutdown` once every in-flight request has completed, and
+   * before the server is closed. Embedders can use it to flush their own
+   * state.
+   */
+  onShutdown?: () => Promise<void>;
 }
 
 export class JsonRpcServer implements IJsonRpcServer {
   private _config: JsonRpcServerConfig;
   private _httpServer: Server;
   private _wsServer: WsT.Server;
+  private _handler: JsonRpcHandler;
 
   constructor(config: JsonRpcServerConfig) {
     const { Server: WSServer } = require("ws") as typeof WsT;
 
     this._config = config;
 
-    const handler = new JsonRpcHandler(config.provider);
+    const handler = new JsonRpcHandler(config.provider, config);
+    this._handler = handler;
 
     this._httpServer = http.createServer();
-    this._wsServer = new WSServer({
+
+    const wsServerOptions: WsT.ServerOptions = {
       server: this._httpServer,
-    });
+      verifyClient: ({ req }) => handler.isPathAllowed(req.url),
+    };
+    if (config.maxRequestBodySize !== undefined) {
+      wsServerOptions.maxPayload = config.maxRequestBodySize;
+    }
+    this._wsServer = new WSServer(wsServerOptions);
 
     this._httpServer.on("request", handler.handleHttp);
     this._wsServer.on("connection", handler.handleWs);
@@ -46,19 +61,18 @@ export class JsonRpcServer implements IJsonRpcServer {
   public getProvider = (name = "json-rpc"): EIP1193Provider => {
     const { Client } = require("undici") as { Client: typeof ClientT };
     const { address, port } = this._httpServer.address() as AddressInfo;
+    const url = `http://${address}:${port}${this._handler.getPathPrefix()}/`;
 
-    const dispatcher = new Client(`http://${address}:${port}/`, {
+    const dispatcher = new Client(url, {
       keepAliveTimeout: 10,
       keepAliveMaxTimeout: 10,
     });
 
-    return new HttpProvider(
-      `http://${address}:${port}/`,
-      name,
-      {},
-      20000,
-      dispatcher
-    );
+    return new HttpProvider(url, name, {}, 20000, dispatcher);
+  };
+
+  public getPathPrefix = (): string => {
+    return this._handler.getPathPrefix();
   };
 
   public listen = (): Promise<{ address: string; port: number }> => {
@@ -84,6 +98,17 @@ export class JsonRpcServer implements IJsonRpcServer {
     await Promise.all([httpServerClosed, wsServerClosed]);
   };
 
+  /**
+   * Stops accepting new requests, waits for the in-flight ones to complete,
+   * runs the `onShutdown` hook and closes the server.
+   */
+  public shutdown = async () => {
+    log("Shutting down JSON-RPC server");
+    await this._handler.stopAcceptingRequests();
+    await this._config.onShutdown?.();
+    await this.close();
+  };
+
   public close = async () => {
     await Promise.all([
       new Promise<void>((resolve, reject) => {
@@ -27,6 +27,8 @@ export type LocalNodeConfig = CommonConfig;
 export interface ForkConfig {
   jsonRpcUrl: string;
   blockNumber?: number;
+  blockHash?: string;
+  blockTag?: "safe" | "finalized";
   httpHeaders?: { [name: string]: string };
 }
 
@@ -47,6 +49,20 @@ export type MempoolOrder = typeof HARDHAT_MEMPOOL_SUPPORTED_ORDERS[number];
 export interface GenesisAccount {
   privateKey: string;
   balance: string | number | bigint;
+  derivationPath?: string;
+}
+
+/**
+ * An account of a genesis alloc, with its fields already encoded as the
+ * params of the hardhat_set* methods.
+ */
+export interface GenesisAllocAccount {
+  address: string;
+  balance?: string;
+  nonce?: string;
+  code?: string;
+  // storage slot => 32-byte value
+  storage: { [slot: string]: string };
 }
 
 export type AccessListBufferItem = [Uint8Array, Uint8Array[]];
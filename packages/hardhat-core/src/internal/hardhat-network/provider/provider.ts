@@ -19,6 +19,12 @@ import type {
   HttpHeader,
 } from "@nomicfoundation/edr";
 import { Common } from "@nomicfoundation/ethereumjs-common";
+import {
+  bigIntToBytes,
+  bytesToHex,
+  generateAddress,
+  generateAddress2,
+} from "@nomicfoundation/ethereumjs-util";
 import picocolors from "picocolors";
 import debug from "debug";
 import { EventEmitter } from "events";
@@ -28,21 +34,31 @@ import semver from "semver";
 
 import { requireNapiRsModule } from "../../../common/napi-rs";
 import {
+  HARDHAT_NETWORK_IMPERSONATE_ACCOUNT_EVENT,
   HARDHAT_NETWORK_RESET_EVENT,
   HARDHAT_NETWORK_REVERT_SNAPSHOT_EVENT,
+  HARDHAT_NETWORK_STOP_IMPERSONATING_ACCOUNT_EVENT,
 } from "../../constants";
 import {
   rpcCompilerInput,
   rpcCompilerOutput,
 } from "../../core/jsonrpc/types/input/solc";
+import {
+  numberToRpcQuantity,
+  rpcAddress,
+  rpcData,
+  rpcHash,
+  rpcQuantity,
+} from "../../core/jsonrpc/types/base-types";
 import { validateParams } from "../../core/jsonrpc/types/input/validation";
 import {
-  InvalidArgumentsError,
   InvalidInputError,
-  ProviderError,
+  MethodNotSupportedError,
+  providerErrorFromJsonRpcError,
 } from "../../core/providers/errors";
 import { isErrorResponse } from "../../core/providers/http";
 import { getHardforkName } from "../../util/hardforks";
+import { optional } from "../../util/io-ts";
 import { createModelsAndDecodeBytecodes } from "../stack-traces/compiler-to-model";
 import { ConsoleLogger } from "../stack-traces/consoleLogger";
 import {
@@ -59,11 +75,13 @@ import { getPackageJson } from "../../util/packageInfo";
 import {
   ForkConfig,
   GenesisAccount,
+  GenesisAllocAccount,
   IntervalMiningConfig,
   MempoolOrder,
   NodeConfig,
   TracingConfig,
 } from "./node-types";
+import { RpcBlockOutput } from "./output";
 import {
   edrRpcDebugTraceToHardhat,
   edrTracingMessageResultToMinimalEVMResult,
@@ -73,7 +91,20 @@ import {
   ethereumjsMempoolOrderToEdrMineOrdering,
   ethereumsjsHardforkToEdrSpecId,
 } from "./utils/convertToEdr";
+import {
+  DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS,
+  DETERMINISTIC_DEPLOYMENT_PROXY_CODE,
+} from "./utils/deterministicDeploymentProxy";
+import { AccountInfo, getAccountsInfo } from "./utils/getAccountsInfo";
 import { makeCommon } from "./utils/makeCommon";
+import {
+  clearForkCapabilitiesProbes,
+  ForkCapabilities,
+  isMissingStateError,
+  missingForkStateError,
+  probeForkCapabilities,
+} from "./utils/probeForkCapabilities";
+import { resolveForkBlockNumber } from "./utils/resolveForkBlockNumber";
 import { LoggerConfig, printLine, replaceLastLine } from "./modules/logger";
 import { MinimalEthereumJsVm, getMinimalEthereumJsVm } from "./vm/minimal-vm";
 
@@ -82,6 +113,19 @@ const log = debug("hardhat:core:hardhat-network:provider");
 /* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
 
 export const DEFAULT_COINBASE = "0xc014ba5ec014ba5ec014ba5ec014ba5ec014ba5e";
+
+// hardhat_waitForFilterChanges polls the filter until it has changes or the
+// timeout expires
+const FILTER_CHANGES_POLL_INTERVAL = 100;
+const DEFAULT_FILTER_CHANGES_TIMEOUT = 10_000;
+const MAX_FILTER_CHANGES_TIMEOUT = 30_000;
+
+// Geth's miner_* methods that are equivalent to a Hardhat Network method
+const GETH_MINER_METHOD_ALIASES = new Map([
+  ["miner_setEtherbase", "hardhat_setCoinbase"],
+  ["miner_setGasLimit", "evm_setBlockGasLimit"],
+]);
+
 let _globalEdrContext: EdrContext | undefined;
 
 // Lazy initialize the global EDR context.
@@ -121,6 +165,9 @@ interface HardhatNetworkProviderConfig {
   forkCachePath?: string;
   enableTransientStorage: boolean;
   enableRip7212: boolean;
+  exposePrivateKeys?: boolean;
+  deterministicDeploymentProxy?: boolean;
+  genesisAlloc?: GenesisAllocAccount[];
 }
 
 export function getNodeConfig(
@@ -152,6 +199,27 @@ export function getNodeConfig(
 
 class EdrProviderEventAdapter extends EventEmitter {}
 
+export interface SnapshotInfo {
+  id: string;
+  label?: string;
+  blockNumber: string;
+  timestamp: string;
+  // When the snapshot was taken, in seconds since the epoch
+  createdAt: string;
+}
+
+// The timestamp of a snapshot's block is only fetched when the snapshots are
+// listed, so that evm_snapshot doesn't need to get the whole block
+type SnapshotRecord = Omit<SnapshotInfo, "timestamp"> & { timestamp?: string };
+
+interface FundedImpersonatedAccount {
+  originalBalance: string;
+
+  // The id of the latest snapshot when the account was funded, if any.
+  // Reverting to it or to an earlier one also reverts the funding.
+  latestSnapshotId?: string;
+}
+
 type CallOverrideCallback = (
   address: Buffer,
   data: Buffer
@@ -165,6 +233,31 @@ export class EdrProviderWrapper
 {
   private _failedStackTraces = 0;
 
+  // The accounts impersonated with hardhat_impersonateAccountWithFunds, with
+  // the balances they had before being funded, indexed by their lowercase
+  // address
+  private _fundedImpersonatedAccounts = new Map<
+    string,
+    FundedImpersonatedAccount
+  >();
+
+  // The fork config with its block number resolved, used to probe the
+  // capabilities of the remote node when they are requested
+  private _forkConfig?: ForkConfig;
+
+  // The latest interval mining config, which miner_start restores after
+  // miner_stop disables it
+  private _intervalMining: IntervalMiningConfig = 0;
+  private _intervalMiningBeforeMinerStop?: IntervalMiningConfig;
+
+  // Wakes up the hardhat_waitForFilterChanges calls that are waiting for the
+  // next poll, so that cancelFilterChangeWaits() doesn't have to wait for them
+  private _filterChangesWaits = new Set<() => void>();
+  private _areFilterChangeWaitsCancelled = false;
+
+  // The snapshots that haven't been reverted yet, in the order they were taken
+  private _snapshots: SnapshotRecord[] = [];
+
   // temporarily added to make smock work with HH+EDR
   private _callOverrideCallback?: CallOverrideCallback;
 
@@ -180,6 +273,9 @@ export class EdrProviderWrapper
     private readonly _vmTraceDecoder: VmTraceDecoderT,
     // The common configuration for EthereumJS VM is not used by EDR, but tests expect it as part of the provider.
     private readonly _common: Common,
+    private readonly _accountsInfo: AccountInfo[],
+    private readonly _deployDeterministicDeploymentProxy: boolean,
+    private readonly _genesisAlloc: GenesisAllocAccount[],
     tracingConfig?: TracingConfig
   ) {
     super();
@@ -201,14 +297,16 @@ export class EdrProviderWrapper
     const coinbase = config.coinbase ?? DEFAULT_COINBASE;
 
     let fork;
+    let resolvedForkConfig: ForkConfig | undefined;
     if (config.forkConfig !== undefined) {
+      const forkConfig = await resolveForkBlockNumber(config.forkConfig);
+      resolvedForkConfig = forkConfig;
+
       let httpHeaders: HttpHeader[] | undefined;
-      if (config.forkConfig.httpHeaders !== undefined) {
+      if (forkConfig.httpHeaders !== undefined) {
         httpHeaders = [];
 
-        for (const [name, value] of Object.entries(
-          config.forkConfig.httpHeaders
-        )) {
+        for (const [name, value] of Object.entries(forkConfig.httpHeaders)) {
           httpHeaders.push({
             name,
             value,
@@ -217,10 +315,10 @@ export class EdrProviderWrapper
       }
 
       fork = {
-        jsonRpcUrl: config.forkConfig.jsonRpcUrl,
+        jsonRpcUrl: forkConfig.jsonRpcUrl,
         blockNumber:
-          config.forkConfig.blockNumber !== undefined
-            ? BigInt(config.forkConfig.blockNumber)
+          forkConfig.blockNumber !== undefined
+            ? BigInt(forkConfig.blockNumber)
             : undefined,
         httpHeaders,
       };
@@ -324,11 +422,18 @@ export class EdrProviderWrapper
     };
 
     const common = makeCommon(getNodeConfig(config));
+    const accountsInfo = getAccountsInfo(
+      config.genesisAccounts,
+      config.exposePrivateKeys ?? false
+    );
     const wrapper = new EdrProviderWrapper(
       provider,
       minimalEthereumJsNode,
       vmTraceDecoder,
       common,
+      accountsInfo,
+      config.deterministicDeploymentProxy ?? false,
+      config.genesisAlloc ?? [],
       tracingConfig
     );
 
@@ -338,6 +443,11 @@ export class EdrProviderWrapper
       wrapper._ethEventListener.bind(wrapper)
     );
 
+    wrapper._forkConfig = resolvedForkConfig;
+    wrapper._intervalMining = config.intervalMining;
+
+    await wrapper._setUpInitialState();
+
     return wrapper;
   }
 
@@ -348,7 +458,7 @@ export class EdrProviderWrapper
       );
     }
 
-    const params = args.params ?? [];
+    let params = args.params ?? [];
 
     if (args.method === "hardhat_addCompilationResult") {
       return this._addCompilationResultAction(
@@ -358,6 +468,52 @@ export class EdrProviderWrapper
       return this._getStackTraceFailuresCountAction(
         ...this._getStackTraceFailuresCountParams(params)
       );
+    } else if (args.method === "hardhat_getAccountsInfo") {
+      return this._getAccountsInfoAction(
+        ...this._getAccountsInfoParams(params)
+      );
+    } else if (args.method === "hardhat_impersonateAccountWithFunds") {
+      return this._impersonateAccountWithFundsAction(
+        ...this._impersonateAccountWithFundsParams(params)
+      );
+    } else if (args.method === "hardhat_waitForFilterChanges") {
+      return this._waitForFilterChangesAction(
+        ...this._waitForFilterChangesParams(params)
+      );
+    } else if (args.method === "hardhat_getCreateAddress") {
+      return this._getCreateAddressAction(
+        ...this._getCreateAddressParams(params)
+      );
+    } else if (args.method === "hardhat_getCreate2Address") {
+      return this._getCreate2AddressAction(
+        ...this._getCreate2AddressParams(params)
+      );
+    } else if (args.method === "hardhat_listSnapshots") {
+      return this._listSnapshotsAction(...this._listSnapshotsParams(params));
+    } else if (args.method === "miner_start") {
+      return this._minerStartAction(...this._minerStartParams(params));
+    } else if (args.method === "miner_stop") {
+      return this._minerStopAction(...this._minerStopParams(params));
+    } else if (args.method === "miner_setGasPrice") {
+      return this._minerSetGasPriceAction(
+        ...this._minerSetGasPriceParams(params)
+      );
+    }
+
+    const minerMethodAlias = GETH_MINER_METHOD_ALIASES.get(args.method);
+    if (minerMethodAlias !== undefined) {
+      return this.request({ method: minerMethodAlias, params });
+    }
+
+    // EDR's evm_snapshot doesn't take any params, so the label is kept here
+    let snapshotLabel: string | undefined;
+    if (args.method === "evm_snapshot") {
+      [snapshotLabel] = this._snapshotParams(params);
+      params = [];
+    }
+
+    if (args.method === "hardhat_reset") {
+      params = await this._resolveResetForkBlockNumber(params);
     }
 
     const stringifiedArgs = JSON.stringify({
@@ -447,15 +603,12 @@ export class EdrProviderWrapper
         (error as any).transactionHash =
           response.error.data?.transactionHash ?? undefined;
       } else {
-        if (response.error.code === InvalidArgumentsError.CODE) {
-          error = new InvalidArgumentsError(response.error.message);
-        } else {
-          error = new ProviderError(
-            response.error.message,
-            response.error.code
-          );
-        }
-        error.data = response.error.data;
+        error = providerErrorFromJsonRpcError(response.error);
+      }
+
+      const forkBlockNumber = this._forkConfig?.blockNumber;
+      if (forkBlockNumber !== undefined && isMissingStateError(error)) {
+        error = missingForkStateError(forkBlockNumber, error);
       }
 
       // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
@@ -463,15 +616,50 @@ export class EdrProviderWrapper
     }
 
     if (args.method === "hardhat_reset") {
+      this._forkConfig = params[0]?.forking;
+      clearForkCapabilitiesProbes();
+      this._fundedImpersonatedAccounts.clear();
+      this._snapshots = [];
+      await this._setUpInitialState();
       this.emit(HARDHAT_NETWORK_RESET_EVENT);
+    } else if (args.method === "evm_setIntervalMining") {
+      this._intervalMining = params[0];
+    } else if (args.method === "evm_snapshot") {
+      await this._recordSnapshot(response.result, snapshotLabel);
     } else if (args.method === "evm_revert") {
+      if (response.result === true) {
+        this._forgetSnapshotsFrom(params[0]);
+        this._forgetFundingsRevertedTo(params[0]);
+      }
+
       this.emit(HARDHAT_NETWORK_REVERT_SNAPSHOT_EVENT);
+    } else if (args.method === "hardhat_impersonateAccount") {
+      this.emit(HARDHAT_NETWORK_IMPERSONATE_ACCOUNT_EVENT, params[0]);
+    } else if (args.method === "hardhat_stopImpersonatingAccount") {
+      await this._restoreFundedImpersonatedAccountBalance(params[0]);
+      this.emit(HARDHAT_NETWORK_STOP_IMPERSONATING_ACCOUNT_EVENT, params[0]);
     }
 
     // Override EDR version string with Hardhat version string with EDR backend,
     // e.g. `HardhatNetwork/2.19.0/@nomicfoundation/edr/0.2.0-dev`
     if (args.method === "web3_clientVersion") {
       return clientVersion(response.result);
+    } else if (
+      args.method === "hardhat_metadata" &&
+      response.result.forkedNetwork !== undefined
+    ) {
+      const capabilities = await this._getForkCapabilities();
+      if (capabilities === undefined) {
+        return response.result;
+      }
+
+      return {
+        ...response.result,
+        forkedNetwork: {
+          ...response.result.forkedNetwork,
+          capabilities,
+        },
+      };
     } else if (
       args.method === "debug_traceTransaction" ||
       args.method === "debug_traceCall"
@@ -491,6 +679,21 @@ export class EdrProviderWrapper
     this._vmTracer = vmTracer;
   }
 
+  /**
+   * Makes the pending `hardhat_waitForFilterChanges` calls return right away,
+   * and the next ones return without waiting for changes. Other methods keep
+   * working.
+   *
+   * Used for internal long-polling tests.
+   */
+  public cancelFilterChangeWaits() {
+    this._areFilterChangeWaitsCancelled = true;
+
+    for (const wakeUp of Array.from(this._filterChangesWaits)) {
+      wakeUp();
+    }
+  }
+
   // temporarily added to make smock work with HH+EDR
   private _setCallOverrideCallback(callback: CallOverrideCallback) {
     this._callOverrideCallback = callback;
@@ -579,6 +782,34 @@ export class EdrProviderWrapper
     return true;
   }
 
+  private async _resolveResetForkBlockNumber(params: any[]): Promise<any[]> {
+    const [resetOptions, ...rest] = params;
+    if (resetOptions?.forking === undefined) {
+      return params;
+    }
+
+    const forking = await resolveForkBlockNumber(resetOptions.forking);
+
+    return [{ ...resetOptions, forking }, ...rest];
+  }
+
+  /**
+   * Probes the remote node lazily, as it needs a request that would break
+   * offline runs that only use the fork cache.
+   */
+  private async _getForkCapabilities(): Promise<ForkCapabilities | undefined> {
+    if (this._forkConfig === undefined) {
+      return undefined;
+    }
+
+    try {
+      return await probeForkCapabilities(this._forkConfig);
+    } catch (error) {
+      log("Couldn't probe the capabilities of the remote node", error);
+      return undefined;
+    }
+  }
+
   private _getStackTraceFailuresCountParams(params: any[]): [] {
     return validateParams(params);
   }
@@ -587,6 +818,373 @@ export class EdrProviderWrapper
     return this._failedStackTraces;
   }
 
+  private _getAccountsInfoParams(params: any[]): [] {
+    return validateParams(params);
+  }
+
+  private _getAccountsInfoAction(): AccountInfo[] {
+    return this._accountsInfo.map((accountInfo) => ({ ...accountInfo }));
+  }
+
+  private _waitForFilterChangesParams(
+    params: any[]
+  ): [bigint, number | undefined] {
+    return validateParams(params, rpcQuantity, optional(t.number));
+  }
+
+  /**
+   * A long-polling version of `eth_getFilterChanges`, for clients that don't
+   * support subscriptions. It only returns an empty array if the filter
+   * didn't have any changes before the timeout.
+   */
+  private async _waitForFilterChangesAction(
+    filterId: bigint,
+    timeout = DEFAULT_FILTER_CHANGES_TIMEOUT
+  ): Promise<unknown[] | null> {
+    const deadline = Date.now() + Math.min(timeout, MAX_FILTER_CHANGES_TIMEOUT);
+
+    while (true) {
+      const changes = (await this.request({
+        method: "eth_getFilterChanges",
+        params: [numberToRpcQuantity(filterId)],
+      })) as unknown[] | null;
+
+      // null means that the filter doesn't exist
+      if (
+        changes === null ||
+        changes.length > 0 ||
+        Date.now() >= deadline ||
+        this._areFilterChangeWaitsCancelled
+      ) {
+        return changes;
+      }
+
+      await new Promise<void>((resolve) => {
+        const wakeUp = () => {
+          clearTimeout(timer);
+          this._filterChangesWaits.delete(wakeUp);
+          resolve();
+        };
+
+        const timer = setTimeout(wakeUp, FILTER_CHANGES_POLL_INTERVAL);
+        this._filterChangesWaits.add(wakeUp);
+      });
+    }
+  }
+
+  private _getCreateAddressParams(params: any[]): [Buffer, bigint | undefined] {
+    return validateParams(params, rpcAddress, optional(rpcQuantity));
+  }
+
+  /**
+   * Returns the address of a contract deployed by `from` with CREATE. If no
+   * nonce is given, the account's next nonce is used.
+   */
+  private async _getCreateAddressAction(
+    from: Buffer,
+    nonce?: bigint
+  ): Promise<string> {
+    if (nonce === undefined) {
+      const pendingNonce = (await this.request({
+        method: "eth_getTransactionCount",
+        params: [bytesToHex(from), "pending"],
+      })) as string;
+
+      nonce = BigInt(pendingNonce);
+    }
+
+    return bytesToHex(generateAddress(from, bigIntToBytes(nonce)));
+  }
+
+  private _getCreate2AddressParams(params: any[]): [Buffer, Buffer, Buffer] {
+    return validateParams(params, rpcAddress, rpcHash, rpcData);
+  }
+
+  /**
+   * Returns the address of a contract deployed by `deployer` with CREATE2.
+   */
+  private _getCreate2AddressAction(
+    deployer: Buffer,
+    salt: Buffer,
+    initCode: Buffer
+  ): string {
+    return bytesToHex(generateAddress2(deployer, salt, initCode));
+  }
+
+  /**
+   * EDR's genesis accounts only have a balance, so the rest of the initial
+   * state is set up here, both when the provider is created and after a
+   * `hardhat_reset`.
+   */
+  private async _setUpInitialState() {
+    await this._applyGenesisAlloc();
+    await this._setUpDeterministicDeploymentProxy();
+  }
+
+  private async _applyGenesisAlloc() {
+    for (const account of this._genesisAlloc) {
+      if (account.balance !== undefined) {
+        await this.request({
+          method: "hardhat_setBalance",
+          params: [account.address, account.balance],
+        });
+      }
+
+      if (account.nonce !== undefined) {
+        // hardhat_setNonce can't decrease a nonce, and a forked account can
+        // already have a higher one than the alloc, in which case it's kept
+        const currentNonce = (await this.request({
+          method: "eth_getTransactionCount",
+          params: [account.address, "latest"],
+        })) as string;
+
+        if (BigInt(account.nonce) > BigInt(currentNonce)) {
+          await this.request({
+            method: "hardhat_setNonce",
+            params: [account.address, account.nonce],
+          });
+        } else {
+          log(
+            `Not setting the nonce of ${account.address} to ${account.nonce}, as it's already ${currentNonce}`
+          );
+        }
+      }
+
+      if (account.code !== undefined) {
+        await this.request({
+          method: "hardhat_setCode",
+          params: [account.address, account.code],
+        });
+      }
+
+      for (const [slot, value] of Object.entries(account.storage)) {
+        await this.request({
+          method: "hardhat_setStorageAt",
+          params: [account.address, slot, value],
+        });
+      }
+    }
+  }
+
+  /**
+   * Deploys the deterministic deployment proxy if it's enabled in the config
+   * and the chain doesn't have it yet, as it can already exist when forking.
+   */
+  private async _setUpDeterministicDeploymentProxy() {
+    if (!this._deployDeterministicDeploymentProxy) {
+      return;
+    }
+
+    const code = await this.request({
+      method: "eth_getCode",
+      params: [DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS, "latest"],
+    });
+
+    if (code !== "0x") {
+      return;
+    }
+
+    await this.request({
+      method: "hardhat_setCode",
+      params: [
+        DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS,
+        DETERMINISTIC_DEPLOYMENT_PROXY_CODE,
+      ],
+    });
+  }
+
+  private _impersonateAccountWithFundsParams(params: any[]): [Buffer, bigint] {
+    return validateParams(params, rpcAddress, rpcQuantity);
+  }
+
+  /**
+   * Sets the balance of an account and impersonates it. Its original balance
+   * is restored by `hardhat_stopImpersonatingAccount`, so that tests don't
+   * permanently change the balance of forked accounts.
+   */
+  private async _impersonateAccountWithFundsAction(
+    addressBuffer: Buffer,
+    balance: bigint
+  ): Promise<true> {
+    const address = bytesToHex(addressBuffer);
+
+    // If the account was already funded, keep the balance it had before that
+    if (!this._fundedImpersonatedAccounts.has(address)) {
+      const originalBalance = (await this.request({
+        method: "eth_getBalance",
+        params: [address, "latest"],
+      })) as string;
+
+      this._fundedImpersonatedAccounts.set(address, {
+        originalBalance,
+        latestSnapshotId: this._snapshots[this._snapshots.length - 1]?.id,
+      });
+    }
+
+    await this.request({
+      method: "hardhat_setBalance",
+      params: [address, numberToRpcQuantity(balance)],
+    });
+    await this.request({
+      method: "hardhat_impersonateAccount",
+      params: [address],
+    });
+
+    return true;
+  }
+
+  private async _restoreFundedImpersonatedAccountBalance(address: string) {
+    const key = address.toLowerCase();
+    const fundedAccount = this._fundedImpersonatedAccounts.get(key);
+    if (fundedAccount === undefined) {
+      return;
+    }
+
+    this._fundedImpersonatedAccounts.delete(key);
+
+    await this.request({
+      method: "hardhat_setBalance",
+      params: [address, fundedAccount.originalBalance],
+    });
+  }
+
+  // Reverting to a snapshot taken before an account was funded already
+  // restores its balance, so it mustn't be restored again when the
+  // impersonation stops, as it could have changed after the revert
+  private _forgetFundingsRevertedTo(snapshotId: string) {
+    for (const [key, fundedAccount] of this._fundedImpersonatedAccounts) {
+      if (
+        fundedAccount.latestSnapshotId !== undefined &&
+        BigInt(fundedAccount.latestSnapshotId) >= BigInt(snapshotId)
+      ) {
+        this._fundedImpersonatedAccounts.delete(key);
+      }
+    }
+  }
+
+  private _snapshotParams(params: any[]): [string | undefined] {
+    return validateParams(params, optional(t.string));
+  }
+
+  private async _recordSnapshot(id: string, label?: string) {
+    const blockNumber = (await this.request({
+      method: "eth_blockNumber",
+    })) as string;
+
+    this._snapshots.push({
+      id,
+      label,
+      blockNumber,
+      createdAt: numberToRpcQuantity(Math.floor(Date.now() / 1000)),
+    });
+  }
+
+  // Reverting to a snapshot deletes it and every snapshot taken after it
+  private _forgetSnapshotsFrom(id: string) {
+    const index = this._snapshots.findIndex(
+      (snapshot) => BigInt(snapshot.id) === BigInt(id)
+    );
+
+    if (index !== -1) {
+      this._snapshots = this._snapshots.slice(0, index);
+    }
+  }
+
+  private _listSnapshotsParams(params: any[]): [] {
+    return validateParams(params);
+  }
+
+  private async _listSnapshotsAction(): Promise<SnapshotInfo[]> {
+    const snapshots: SnapshotInfo[] = [];
+
+    for (const snapshot of this._snapshots) {
+      // The blocks of the snapshots that can still be reverted to are never
+      // removed, so their timestamps can be fetched at any point
+      if (snapshot.timestamp === undefined) {
+        const block = (await this.request({
+          method: "eth_getBlockByNumber",
+          params: [snapshot.blockNumber, false],
+        })) as RpcBlockOutput;
+
+        snapshot.timestamp = block.timestamp;
+      }
+
+      snapshots.push({
+        id: snapshot.id,
+        label: snapshot.label,
+        blockNumber: snapshot.blockNumber,
+        timestamp: snapshot.timestamp,
+        createdAt: snapshot.createdAt,
+      });
+    }
+
+    return snapshots;
+  }
+
+  // Older geth versions take the number of mining threads, which we ignore
+  private _minerStartParams(params: any[]): [number | undefined] {
+    return validateParams(params, optional(t.number));
+  }
+
+  private async _minerStartAction(_threads?: number): Promise<null> {
+    await this.request({ method: "evm_setAutomine", params: [true] });
+
+    if (this._intervalMiningBeforeMinerStop !== undefined) {
+      await this.request({
+        method: "evm_setIntervalMining",
+        params: [this._intervalMiningBeforeMinerStop],
+      });
+
+      this._intervalMiningBeforeMinerStop = undefined;
+    }
+
+    return null;
+  }
+
+  private _minerStopParams(params: any[]): [] {
+    return validateParams(params);
+  }
+
+  private async _minerStopAction(): Promise<null> {
+    // Calling miner_stop twice shouldn't lose the config to restore
+    const intervalMining =
+      this._intervalMiningBeforeMinerStop ?? this._intervalMining;
+
+    await this.request({ method: "evm_setAutomine", params: [false] });
+    await this.request({ method: "evm_setIntervalMining", params: [0] });
+
+    this._intervalMiningBeforeMinerStop = intervalMining;
+
+    return null;
+  }
+
+  private _minerSetGasPriceParams(params: any[]): [bigint] {
+    return validateParams(params, rpcQuantity);
+  }
+
+  /**
+   * Geth's miner_setGasPrice is an alias of hardhat_setMinGasPrice, which
+   * only makes sense before EIP-1559, so it's rejected after London instead
+   * of failing with an error about another method.
+   */
+  private async _minerSetGasPriceAction(gasPrice: bigint): Promise<unknown> {
+    const latestBlock = (await this.request({
+      method: "eth_getBlockByNumber",
+      params: ["latest", false],
+    })) as RpcBlockOutput;
+
+    if (latestBlock.baseFeePerGas !== undefined) {
+      throw MethodNotSupportedError.fromMessage(
+        "Method miner_setGasPrice is not supported when EIP-1559 is active. Use hardhat_setNextBlockBaseFeePerGas to change the fees of the next block instead."
+      );
+    }
+
+    return this.request({
+      method: "hardhat_setMinGasPrice",
+      params: [numberToRpcQuantity(gasPrice)],
+    });
+  }
+
   private async _rawTraceToSolidityStackTrace(
     rawTrace: RawTrace
   ): Promise<SolidityStackTrace | undefined> {
@@ -0,0 +1,11 @@
+/**
+ * The address and runtime code of the deterministic deployment proxy
+ * (https://github.com/Arachnid/deterministic-deployment-proxy), which is
+ * deployed at the same address on most chains and used by many tools to
+ * deploy contracts with CREATE2.
+ */
+export const DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS =
+  "0x4e59b44847b379578588920ca78fbf26c0b4956c";
+
+export const DETERMINISTIC_DEPLOYMENT_PROXY_CODE =
+  "0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe03601600081602082378035828234f58015156039578182fd5b8082525050506014600cf3";
@@ -0,0 +1,114 @@
+import fsExtra from "fs-extra";
+
+import { HardhatError } from "../../../core/errors";
+import { ERRORS } from "../../../core/errors-list";
+import {
+  numberToRpcQuantity,
+  numberToRpcStorageSlot,
+} from "../../../core/jsonrpc/types/base-types";
+import { GenesisAllocAccount } from "../node-types";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+const ADDRESS_REGEX = /^(0x)?[0-9a-fA-F]{40}$/;
+const HEX_DATA_REGEX = /^0x([0-9a-fA-F]{2})*$/;
+
+/**
+ * Parses the `alloc` field of a geth-style genesis file. A file that only
+ * has the alloc object is also accepted.
+ *
+ * Balances, nonces, storage slots and storage values can be decimal or
+ * 0x-prefixed hex, like geth accepts them.
+ */
+export function parseGenesisAlloc(genesis: any): GenesisAllocAccount[] {
+  const alloc = genesis?.alloc ?? genesis;
+  if (typeof alloc !== "object" || alloc === null || Array.isArray(alloc)) {
+    throw new Error("Expected an object with the accounts to allocate");
+  }
+
+  return Object.entries(alloc).map(([address, account]: [string, any]) => {
+    if (!ADDRESS_REGEX.test(address)) {
+      throw new Error(`Invalid address ${address}`);
+    }
+
+    const normalizedAddress = address.startsWith("0x")
+      ? address.toLowerCase()
+      : `0x${address.toLowerCase()}`;
+
+    if (typeof account !== "object" || account === null) {
+      throw new Error(`Invalid account ${address}`);
+    }
+
+    const allocAccount: GenesisAllocAccount = {
+      address: normalizedAddress,
+      storage: {},
+    };
+
+    if (account.balance !== undefined) {
+      allocAccount.balance = numberToRpcQuantity(
+        parseNumber(account.balance, `balance of ${address}`)
+      );
+    }
+
+    if (account.nonce !== undefined) {
+      allocAccount.nonce = numberToRpcQuantity(
+        parseNumber(account.nonce, `nonce of ${address}`)
+      );
+    }
+
+    if (account.code !== undefined) {
+      if (
+        typeof account.code !== "string" ||
+        !HEX_DATA_REGEX.test(account.code)
+      ) {
+        throw new Error(`Invalid code of ${address}`);
+      }
+
+      allocAccount.code = account.code;
+    }
+
+    for (const [slot, value] of Object.entries(account.storage ?? {})) {
+      const slotNumber = parseNumber(
+        slot,
+        `storage slot ${slot} of ${address}`
+      );
+      const valueNumber = parseNumber(
+        value,
+        `value of storage slot ${slot} of ${address}`
+      );
+
+      allocAccount.storage[numberToRpcQuantity(slotNumber)] =
+        numberToRpcStorageSlot(valueNumber);
+    }
+
+    return allocAccount;
+  });
+}
+
+function parseNumber(value: unknown, description: string): bigint {
+  if (typeof value === "number" && Number.isSafeInteger(value) && value >= 0) {
+    return BigInt(value);
+  }
+
+  if (typeof value === "string" && /^(0x[0-9a-fA-F]+|[0-9]+)$/.test(value)) {
+    return BigInt(value);
+  }
+
+  throw new Error(`Invalid ${description}`);
+}
+
+export function readGenesisAllocFile(filePath: string): GenesisAllocAccount[] {
+  try {
+    return parseGenesisAlloc(fsExtra.readJsonSync(filePath));
+  } catch (error) {
+    if (error instanceof Error) {
+      throw new HardhatError(
+        ERRORS.NETWORK.INVALID_GENESIS_ALLOC,
+        { path: filePath, error: error.message },
+        error
+      );
+    }
+
+    throw error;
+  }
+}
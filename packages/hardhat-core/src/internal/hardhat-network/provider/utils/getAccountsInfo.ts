@@ -0,0 +1,51 @@
+import {
+  bytesToHex,
+  privateToAddress,
+  privateToPublic,
+  toBytes,
+} from "@nomicfoundation/ethereumjs-util";
+
+import { GenesisAccount } from "../node-types";
+
+export interface AccountInfo {
+  address: string;
+
+  /**
+   * The uncompressed public key, prefixed with `0x04`.
+   */
+  publicKey: string;
+
+  /**
+   * Only present if the account was derived from a mnemonic.
+   */
+  derivationPath?: string;
+
+  /**
+   * Only present if `exposePrivateKeys` is enabled in the config.
+   */
+  privateKey?: string;
+}
+
+export function getAccountsInfo(
+  genesisAccounts: GenesisAccount[],
+  exposePrivateKeys: boolean
+): AccountInfo[] {
+  return genesisAccounts.map((genesisAccount) => {
+    const privateKey = toBytes(genesisAccount.privateKey);
+
+    const accountInfo: AccountInfo = {
+      address: bytesToHex(privateToAddress(privateKey)),
+      publicKey: `0x04${bytesToHex(privateToPublic(privateKey)).slice(2)}`,
+    };
+
+    if (genesisAccount.derivationPath !== undefined) {
+      accountInfo.derivationPath = genesisAccount.derivationPath;
+    }
+
+    if (exposePrivateKeys) {
+      accountInfo.privateKey = bytesToHex(privateKey);
+    }
+
+    return accountInfo;
+  });
+}
@@ -0,0 +1,141 @@
+import debug from "debug";
+
+import { EIP1193Provider } from "../../../../types";
+import { HARDHAT_NETWORK_NAME } from "../../../constants";
+import { numberToRpcQuantity } from "../../../core/jsonrpc/types/base-types";
+import { ProviderError } from "../../../core/providers/errors";
+import { HttpProvider } from "../../../core/providers/http";
+import { ForkConfig } from "../node-types";
+
+const log = debug("hardhat:core:hardhat-network:fork-probe");
+
+const FORK_HTTP_TIMEOUT = 35000;
+const ZERO_ADDRESS = "0x0000000000000000000000000000000000000000";
+
+// The errors that nodes return when they don't have the state of a block
+const MISSING_STATE_ERROR_PATTERNS = [
+  /missing trie node/i,
+  /header not found/i,
+  /state (is )?not available/i,
+  /historical state .*(unavailable|not available)/i,
+  /pruned/i,
+];
+
+export interface ForkCapabilities {
+  /**
+   * Whether the remote node can return the state at the fork block. Nodes
+   * that aren't archive nodes can only do it for recent blocks.
+   */
+  historicalState: boolean;
+}
+
+// The results of the probes, indexed by fork url and block number
+const probes = new Map<string, Promise<ForkCapabilities>>();
+
+/**
+ * Checks what the remote node of a fork supports. Each url and block number
+ * is only probed once, until the probes are cleared.
+ *
+ * Forks from the latest block don't need historical state, so they aren't
+ * probed.
+ *
+ * Errors that don't say anything about the node's capabilities, like
+ * network errors, are thrown, and the next call probes the node again.
+ */
+export async function probeForkCapabilities(
+  forkConfig: ForkConfig
+): Promise<ForkCapabilities> {
+  const blockNumber = forkConfig.blockNumber;
+  if (blockNumber === undefined) {
+    return { historicalState: true };
+  }
+
+  const key = `${forkConfig.jsonRpcUrl}@${blockNumber}`;
+
+  let probe = probes.get(key);
+  if (probe === undefined) {
+    const forkProvider = new HttpProvider(
+      forkConfig.jsonRpcUrl,
+      HARDHAT_NETWORK_NAME,
+      forkConfig.httpHeaders,
+      FORK_HTTP_TIMEOUT
+    );
+
+    probe = hasHistoricalState(forkProvider, blockNumber).then(
+      (historicalState) => {
+        if (!historicalState) {
+          log(
+            `The remote node ${forkConfig.jsonRpcUrl} doesn't have the state at block ${blockNumber}`
+          );
+        }
+
+        return { historicalState };
+      }
+    );
+
+    probes.set(key, probe);
+    probe.catch(() => probes.delete(key));
+  }
+
+  return probe;
+}
+
+/**
+ * Forgets the results of the previous probes, so that the remote nodes are
+ * probed again. Used when Hardhat Network is reset.
+ */
+export function clearForkCapabilitiesProbes() {
+  probes.clear();
+}
+
+/**
+ * Returns whether the remote node has the state of a block. Only the errors
+ * returned by nodes that don't have it are interpreted as a `false`, the
+ * rest are thrown.
+ */
+export async function hasHistoricalState(
+  forkProvider: EIP1193Provider,
+  blockNumber: number
+): Promise<boolean> {
+  try {
+    await forkProvider.request({
+      method: "eth_getBalance",
+      params: [ZERO_ADDRESS, numberToRpcQuantity(blockNumber)],
+    });
+
+    return true;
+  } catch (error) {
+    if (isMissingStateError(error)) {
+      return false;
+    }
+
+    // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+    throw error;
+  }
+}
+
+/**
+ * Returns whether an error is one of the errors that nodes return when they
+ * don't have the state of a block.
+ */
+export function isMissingStateError(error: unknown): error is ProviderError {
+  return (
+    ProviderError.isProviderError(error) &&
+    MISSING_STATE_ERROR_PATTERNS.some((pattern) => pattern.test(error.message))
+  );
+}
+
+/**
+ * Wraps a missing-state error returned by the remote node into one that
+ * explains why it happened.
+ */
+export function missingForkStateError(
+  forkBlockNumber: number,
+  parent: ProviderError
+): ProviderError {
+  return new ProviderError(
+    `The remote node doesn't have the state that Hardhat Network needs to fork from block ${forkBlockNumber}. Forking from an old block requires an archive node. Remote node error: ${parent.message}`,
+    parent.code,
+    parent
+  );
+}
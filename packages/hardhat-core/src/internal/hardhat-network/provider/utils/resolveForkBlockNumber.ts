@@ -0,0 +1,116 @@
+import { EIP1193Provider } from "../../../../types";
+import { HARDHAT_NETWORK_NAME } from "../../../constants";
+import {
+  numberToRpcQuantity,
+  rpcQuantityToNumber,
+} from "../../../core/jsonrpc/types/base-types";
+import { InvalidInputError } from "../../../core/providers/errors";
+import { HttpProvider } from "../../../core/providers/http";
+import { ForkConfig } from "../node-types";
+import { RpcBlockOutput } from "../output";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+const FORK_HTTP_TIMEOUT = 35000;
+
+/**
+ * EDR only knows how to fork from a block number, so a fork config that
+ * selects its block by hash or tag has to be resolved against the remote
+ * node first.
+ *
+ * The returned config has a `blockNumber` and no `blockHash` or `blockTag`.
+ * Configs that don't use a hash or tag are returned as they are.
+ *
+ * The remote node is accessed through `forkProvider`, which defaults to an
+ * HTTP provider for the config's `jsonRpcUrl`.
+ */
+export async function resolveForkBlockNumber(
+  forkConfig: ForkConfig,
+  forkProvider?: EIP1193Provider
+): Promise<ForkConfig> {
+  const { blockHash, blockTag, ...resolvedForkConfig } = forkConfig;
+
+  if (blockHash === undefined && blockTag === undefined) {
+    return forkConfig;
+  }
+
+  forkProvider ??= new HttpProvider(
+    forkConfig.jsonRpcUrl,
+    HARDHAT_NETWORK_NAME,
+    forkConfig.httpHeaders,
+    FORK_HTTP_TIMEOUT
+  );
+
+  if (blockHash !== undefined) {
+    resolvedForkConfig.blockNumber = await getBlockNumberByHash(
+      forkProvider,
+      blockHash
+    );
+  } else if (blockTag !== undefined) {
+    resolvedForkConfig.blockNumber = await getBlockNumberByTag(
+      forkProvider,
+      blockTag
+    );
+  }
+
+  return resolvedForkConfig;
+}
+
+async function getBlockNumberByHash(
+  forkProvider: EIP1193Provider,
+  blockHash: string
+): Promise<number> {
+  const block = (await forkProvider.request({
+    method: "eth_getBlockByHash",
+    params: [blockHash, false],
+  })) as RpcBlockOutput | null;
+
+  if (block === null || block.number === null) {
+    throw new InvalidInputError(
+      `Trying to fork from block ${blockHash}, but the remote node doesn't have a block with that hash`
+    );
+  }
+
+  const blockNumber = rpcQuantityToNumber(block.number);
+
+  // A block that was reorged out can still be returned by its hash, so we
+  // make sure that it's the canonical block at that height.
+  const canonicalBlock = (await forkProvider.request({
+    method: "eth_getBlockByNumber",
+    params: [numberToRpcQuantity(blockNumber), false],
+  })) as RpcBlockOutput | null;
+
+  if (canonicalBlock?.hash?.toLowerCase() !== blockHash.toLowerCase()) {
+    throw new InvalidInputError(
+      `Trying to fork from block ${blockHash}, but it isn't part of the remote node's canonical chain anymore`
+    );
+  }
+
+  return blockNumber;
+}
+
+async function getBlockNumberByTag(
+  forkProvider: EIP1193Provider,
+  blockTag: "safe" | "finalized"
+): Promise<number> {
+  let block: RpcBlockOutput | null;
+  try {
+    block = (await forkProvider.request({
+      method: "eth_getBlockByNumber",
+      params: [blockTag, false],
+    })) as RpcBlockOutput | null;
+  } catch (error) {
+    throw new InvalidInputError(
+      `Trying to fork from the '${blockTag}' block, but the remote node doesn't support that block tag`,
+      error instanceof Error ? error : undefined
+    );
+  }
+
+  if (block === null || block.number === null) {
+    throw new InvalidInputError(
+      `Trying to fork from the '${blockTag}' block, but the remote node doesn't have one yet`
+    );
+  }
+
+  return rpcQuantityToNumber(block.number);
+}
@@ -109,3 +109,13 @@ export function isSuccessfulJsonResponse(
 ): payload is SuccessfulJsonRpcResponse {
   return "result" in payload;
 }
+
+/**
+ * Returns the namespace of a JSON-RPC method, e.g. `eth` for
+ * `eth_blockNumber`.
+ */
+export function getMethodNamespace(method: string): string {
+  const separatorIndex = method.indexOf("_");
+
+  return separatorIndex === -1 ? method : method.slice(0, separatorIndex);
+}
@@ -3,4 +3,15 @@ export interface JsonRpcServer {
   waitUntilClosed(): Promise<void>;
 
   close(): Promise<void>;
+
+  /**
+   * Like `close`, but waits for in-flight requests to complete first.
+   */
+  shutdown?(): Promise<void>;
+
+  /**
+   * Returns the path under which JSON-RPC requests are served, which is
+   * either empty or starts with a slash and doesn't end with one.
+   */
+  getPathPrefix?(): string;
 }
@@ -59,6 +59,10 @@ export interface HardhatNetworkUserConfig {
   chains?: HardhatNetworkChainsUserConfig;
   enableTransientStorage?: boolean;
   enableRip7212?: boolean;
+  exposePrivateKeys?: boolean;
+  deterministicDeploymentProxy?: boolean;
+  genesisAlloc?: string;
+  server?: HardhatNetworkServerConfig;
 }
 
 export type HardhatNetworkAccountsUserConfig =
@@ -91,6 +95,8 @@ export interface HardhatNetworkForkingUserConfig {
   enabled?: boolean;
   url: string;
   blockNumber?: number;
+  blockHash?: string;
+  blockTag?: HardhatNetworkForkingBlockTag;
   httpHeaders?: { [name: string]: string };
 }
 
@@ -109,6 +115,7 @@ export interface HttpNetworkUserConfig {
   timeout?: number;
   httpHeaders?: { [name: string]: string };
   accounts?: HttpNetworkAccountsUserConfig;
+  allowUnprotectedTxs?: boolean;
 }
 
 export interface NetworksConfig {
@@ -157,6 +164,10 @@ export interface HardhatNetworkConfig {
   allowBlocksWithSameTimestamp?: boolean;
   enableTransientStorage?: boolean;
   enableRip7212?: boolean;
+  exposePrivateKeys?: boolean;
+  deterministicDeploymentProxy?: boolean;
+  genesisAlloc?: string;
+  server?: HardhatNetworkServerConfig;
 }
 
 export type HardhatNetworkAccountsConfig =
@@ -177,13 +188,66 @@ export interface HardhatNetworkHDAccountsConfig {
   passphrase: string;
 }
 
+export type HardhatNetworkForkingBlockTag = "safe" | "finalized";
+
 export interface HardhatNetworkForkingConfig {
   enabled: boolean;
   url: string;
   blockNumber?: number;
+  blockHash?: string;
+  blockTag?: HardhatNetworkForkingBlockTag;
   httpHeaders?: { [name: string]: string };
 }
 
+// The options of the JSON-RPC server started by `hardhat node`. They are
+// used as they are, so there's no separate user config type.
+export interface HardhatNetworkServerConfig {
+  maxBatchSize?: number;
+  maxRequestBodySize?: number;
+  rateLimit?: HardhatNetworkServerRateLimitConfig;
+  auth?: HardhatNetworkServerAuthConfig;
+  methods?: HardhatNetworkServerMethodsConfig;
+  corsOrigins?: string[];
+  pathPrefix?: string;
+  // Only meant for testing how clients deal with an unreliable node
+  faultInjection?: HardhatNetworkServerFaultInjectionConfig;
+}
+
+export interface HardhatNetworkServerRateLimitConfig {
+  maxRequests: number;
+  windowMs: number;
+  trustProxy?: boolean;
+}
+
+export interface HardhatNetworkServerAuthConfig {
+  jwtSecret?: string;
+  apiKeys?: HardhatNetworkServerApiKeyConfig[];
+  publicNamespaces?: string[];
+}
+
+export interface HardhatNetworkServerApiKeyConfig {
+  key: string;
+  namespaces?: string[];
+}
+
+export interface HardhatNetworkServerMethodsConfig {
+  enabled?: string[];
+  disabled?: string[];
+}
+
+export interface HardhatNetworkServerFaultInjectionConfig {
+  methods?: {
+    [method: string]: HardhatNetworkServerFaultInjectionMethodConfig;
+  };
+  dropSubscriptionEventRate?: number;
+}
+
+export interface HardhatNetworkServerFaultInjectionMethodConfig {
+  latencyMs?: number;
+  jitterMs?: number;
+  errorRate?: number;
+}
+
 export interface HttpNetworkConfig {
   chainId?: number;
   from?: string;
@@ -194,6 +258,7 @@ export interface HttpNetworkConfig {
   timeout: number;
   httpHeaders: { [name: string]: string };
   accounts: HttpNetworkAccountsConfig;
+  allowUnprotectedTxs?: boolean;
 }
 
 export type HttpNetworkAccountsConfig =
@@ -1,10 +1,35 @@
+import { assert } from "chai";
+import { request } from "undici";
+
 import {
   TASK_NODE,
   TASK_NODE_SERVER_READY,
 } from "../../src/builtin-tasks/task-names";
+import { HARDHAT_NETWORK_NAME } from "../../src/internal/constants";
+import {
+  LimitExceededError,
+  UnauthorizedError,
+} from "../../src/internal/core/providers/errors";
+import { JsonRpcServer } from "../../src/types";
 import { useEnvironment } from "../helpers/environment";
 import { useFixtureProject } from "../helpers/project";
 
+async function sendJsonRpcBatch(
+  url: string,
+  methods: string[],
+  headers: Record<string, string> = {}
+): Promise<any> {
+  const response = await request(url, {
+    method: "POST",
+    body: JSON.stringify(
+      methods.map((method, id) => ({ jsonrpc: "2.0", id, method, params: [] }))
+    ),
+    headers: { "Content-Type": "application/json", ...headers },
+  });
+
+  return response.body.json();
+}
+
 describe("node task", () => {
   useFixtureProject("default-config-project");
   useEnvironment();
@@ -17,4 +42,107 @@ describe("node task", () => {
     await this.env.run(TASK_NODE);
     // NB: If a file watcher persists past this test, then mocha will fail to exit cleanly.
   });
+
+  describe("server config", function () {
+    it("should apply the limits of the server config", async function () {
+      this.env.config.networks[HARDHAT_NETWORK_NAME].server = {
+        maxBatchSize: 1,
+      };
+
+      let response: any;
+      this.env.tasks[TASK_NODE_SERVER_READY].setAction(
+        async ({
+          address,
+          port,
+          server,
+        }: {
+          address: string;
+          port: number;
+          server: JsonRpcServer;
+        }) => {
+          response = await sendJsonRpcBatch(`http://${address}:${port}/`, [
+            "eth_chainId",
+            "eth_chainId",
+          ]);
+
+          await server.close();
+        }
+      );
+
+      await this.env.run(TASK_NODE, { port: 0 });
+
+      assert.equal(response.error.code, LimitExceededError.CODE);
+    });
+
+    it("should require the credentials of the auth config", async function () {
+      this.env.config.networks[HARDHAT_NETWORK_NAME].server = {
+        auth: { apiKeys: [{ key: "secret-key" }] },
+      };
+
+      let unauthenticatedResponse: any;
+      let authenticatedResponse: any;
+      this.env.tasks[TASK_NODE_SERVER_READY].setAction(
+        async ({
+          address,
+          port,
+          server,
+        }: {
+          address: string;
+          port: number;
+          server: JsonRpcServer;
+        }) => {
+          const url = `http://${address}:${port}/`;
+
+          [unauthenticatedResponse] = await sendJsonRpcBatch(url, [
+            "eth_chainId",
+          ]);
+          [authenticatedResponse] = await sendJsonRpcBatch(
+            url,
+            ["eth_chainId"],
+            { Authorization: "Bearer secret-key" }
+          );
+
+          await server.close();
+        }
+      );
+
+      await this.env.run(TASK_NODE, { port: 0 });
+
+      assert.equal(unauthenticatedResponse.error.code, UnauthorizedError.CODE);
+      assert.equal(authenticatedResponse.result, "0x7a69");
+    });
+
+    it("should serve JSON-RPC under the path prefix param", async function () {
+      this.env.config.networks[HARDHAT_NETWORK_NAME].server = {
+        pathPrefix: "/ignored",
+      };
+
+      let pathPrefix: string | undefined;
+      let response: any;
+      this.env.tasks[TASK_NODE_SERVER_READY].setAction(
+        async ({
+          address,
+          port,
+          server,
+        }: {
+          address: string;
+          port: number;
+          server: JsonRpcServer;
+        }) => {
+          pathPrefix = server.getPathPrefix?.();
+          [response] = await sendJsonRpcBatch(
+            `http://${address}:${port}/rpc/`,
+            ["eth_chainId"]
+          );
+
+          await server.close();
+        }
+      );
+
+      await this.env.run(TASK_NODE, { port: 0, pathPrefix: "rpc" });
+
+      assert.equal(pathPrefix, "/rpc");
+      assert.equal(response.result, "0x7a69");
+    });
+  });
 });
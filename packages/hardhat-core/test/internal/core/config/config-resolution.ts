@@ -448,6 +448,47 @@ describe("Config resolution", () => {
             httpHeaders: {},
           });
         });
+
+        it("Should let you specify a blockHash", function () {
+          const blockHash = `0x${"ab".repeat(32)}`;
+          const config = resolveConfig(__filename, {
+            networks: {
+              hardhat: {
+                forking: {
+                  url: "asd",
+                  blockHash,
+                },
+              },
+            },
+          });
+
+          assert.deepEqual(config.networks.hardhat.forking, {
+            url: "asd",
+            enabled: true,
+            blockHash,
+            httpHeaders: {},
+          });
+        });
+
+        it("Should let you specify a blockTag", function () {
+          const config = resolveConfig(__filename, {
+            networks: {
+              hardhat: {
+                forking: {
+                  url: "asd",
+                  blockTag: "finalized",
+                },
+              },
+            },
+          });
+
+          assert.deepEqual(config.networks.hardhat.forking, {
+            url: "asd",
+            enabled: true,
+            blockTag: "finalized",
+            httpHeaders: {},
+          });
+        });
       });
 
       describe("Accounts settings", function () {
@@ -880,6 +880,58 @@ describe("Config validation", function () {
               }),
             ERRORS.GENERAL.INVALID_CONFIG
           );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: { url: "asd", blockHash: "0x1234" },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: { url: "asd", blockTag: "latest" },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: { url: "asd", blockNumber: 123, blockTag: "safe" },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: {
+                      url: "asd",
+                      blockHash: `0x${"ab".repeat(32)}`,
+                      blockTag: "finalized",
+                    },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
         });
 
         describe("HardhatNetworkHDAccounstConfig", function () {
@@ -1160,6 +1212,158 @@ describe("Config validation", function () {
             );
           });
         });
+
+        describe("Hardhat network's server", function () {
+          it("Should accept valid limits", function () {
+            assert.isEmpty(
+              getValidationErrors({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    server: {
+                      maxBatchSize: 10,
+                      maxRequestBodySize: 1_000_000,
+                      rateLimit: {
+                        maxRequests: 100,
+                        windowMs: 1000,
+                        trustProxy: true,
+                      },
+                    },
+                  },
+                },
+              })
+            );
+          });
+
+          it("Should fail if a limit isn't a positive integer", function () {
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { maxBatchSize: 0 },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { maxRequestBodySize: 1.5 },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: {
+                        rateLimit: { maxRequests: -1, windowMs: 1000 },
+                      },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+          });
+
+          it("Should accept an auth config", function () {
+            assert.isEmpty(
+              getValidationErrors({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    server: {
+                      auth: {
+                        jwtSecret: "ab".repeat(32),
+                        apiKeys: [{ key: "key", namespaces: ["eth"] }],
+                        publicNamespaces: ["net"],
+                      },
+                    },
+                  },
+                },
+              })
+            );
+          });
+
+          it("Should fail if an API key doesn't have a key", function () {
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { auth: { apiKeys: [{ namespaces: ["eth"] }] } },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+          });
+
+          it("Should fail if the methods aren't arrays of strings", function () {
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { methods: { disabled: "hardhat" } },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+          });
+
+          it("Should fail if a fault injection rate isn't between 0 and 1", function () {
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: {
+                        faultInjection: { methods: { "*": { errorRate: 2 } } },
+                      },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: {
+                        faultInjection: { dropSubscriptionEventRate: -0.5 },
+                      },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+          });
+
+          it("Should fail if the rate limit is incomplete", function () {
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { rateLimit: { maxRequests: 10 } },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+          });
+        });
       });
 
       describe("HTTP network config", function () {
@@ -1,5 +1,8 @@
 import { Common } from "@nomicfoundation/ethereumjs-common";
-import { AccessListEIP2930Transaction } from "@nomicfoundation/ethereumjs-tx";
+import {
+  AccessListEIP2930Transaction,
+  LegacyTransaction,
+} from "@nomicfoundation/ethereumjs-tx";
 import { assert } from "chai";
 import {
   bytesToHex as bufferToHex,
@@ -345,6 +348,61 @@ describe("Local accounts provider", () => {
     validateRawEIP2930Transaction(expectedRaw, tx);
   });
 
+  describe("unprotected transactions", () => {
+    const tx = {
+      from: "0xb5bc06d4548a3ac17d72b372ae1e416bf65b8ead",
+      to: "0xb5bc06d4548a3ac17d72b372ae1e416bf65b8ead",
+      gas: numberToRpcQuantity(21000),
+      gasPrice: numberToRpcQuantity(678912),
+      nonce: numberToRpcQuantity(0),
+      value: numberToRpcQuantity(1),
+    };
+
+    async function getSentLegacyTransaction(provider: EIP1193Provider) {
+      await provider.request({
+        method: "eth_sendTransaction",
+        params: [tx],
+      });
+
+      const rawTransaction = mock.getLatestParams("eth_sendRawTransaction")[0];
+
+      return LegacyTransaction.fromSerializedTx(toBuffer(rawTransaction));
+    }
+
+    it("Should sign legacy transactions with replay protection by default", async () => {
+      const sentTx = await getSentLegacyTransaction(wrapper);
+
+      assert.oneOf(sentTx.v, [
+        BigInt(MOCK_PROVIDER_CHAIN_ID * 2 + 35),
+        BigInt(MOCK_PROVIDER_CHAIN_ID * 2 + 36),
+      ]);
+    });
+
+    it("Should sign legacy transactions without replay protection if allowUnprotectedTxs is enabled", async () => {
+      const provider = new LocalAccountsProvider(mock, accounts, true);
+      const sentTx = await getSentLegacyTransaction(provider);
+
+      assert.oneOf(sentTx.v, [27n, 28n]);
+      assert.equal(sentTx.getSenderAddress().toString(), tx.from);
+    });
+
+    it("Should keep replay protection in typed transactions", async () => {
+      const provider = new LocalAccountsProvider(mock, accounts, true);
+
+      await provider.request({
+        method: "eth_sendTransaction",
+        params: [{ ...tx, accessList: [] }],
+      });
+
+      const rawTransaction = mock.getLatestParams("eth_sendRawTransaction")[0];
+      const sentTx = AccessListEIP2930Transaction.fromSerializedTx(
+        toBuffer(rawTransaction)
+      );
+
+      assert.equal(sentTx.chainId, BigInt(MOCK_PROVIDER_CHAIN_ID));
+    });
+  });
+
   describe("eth_sign", () => {
     it("Should be compatible with parity's implementation", async () => {
       // This test was created by using Parity Ethereum
@@ -2,6 +2,15 @@ import { assert } from "chai";
 import path from "path";
 
 import { numberToRpcQuantity } from "../../../../src/internal/core/jsonrpc/types/base-types";
+import {
+  InvalidArgumentsError,
+  LimitExceededError,
+  MethodNotSupportedError,
+  ProviderError,
+  ResourceNotFoundError,
+  TransactionExecutionError,
+  providerErrorFromJsonRpcError,
+} from "../../../../src/internal/core/providers/errors";
 import {
   EXAMPLE_CONTRACT,
   EXAMPLE_REVERT_CONTRACT,
@@ -85,3 +94,70 @@ describe("provider errors", function () {
     });
   });
 });
+
+describe("providerErrorFromJsonRpcError", function () {
+  it("should create the error class that matches the code", function () {
+    assert.instanceOf(
+      providerErrorFromJsonRpcError({
+        code: InvalidArgumentsError.CODE,
+        message: "invalid params",
+      }),
+      InvalidArgumentsError
+    );
+    assert.instanceOf(
+      providerErrorFromJsonRpcError({
+        code: ResourceNotFoundError.CODE,
+        message: "not found",
+      }),
+      ResourceNotFoundError
+    );
+    assert.instanceOf(
+      providerErrorFromJsonRpcError({
+        code: TransactionExecutionError.CODE,
+        message: "rejected",
+      }),
+      TransactionExecutionError
+    );
+    assert.instanceOf(
+      providerErrorFromJsonRpcError({
+        code: LimitExceededError.CODE,
+        message: "limit exceeded",
+      }),
+      LimitExceededError
+    );
+  });
+
+  it("should keep the message of method not supported errors", function () {
+    const error = providerErrorFromJsonRpcError({
+      code: MethodNotSupportedError.CODE,
+      message: "Method eth_getProof is not supported",
+    });
+
+    assert.instanceOf(error, MethodNotSupportedError);
+    assert.equal(error.code, MethodNotSupportedError.CODE);
+    assert.equal(error.message, "Method eth_getProof is not supported");
+  });
+
+  it("should fall back to a ProviderError for unknown codes", function () {
+    const error = providerErrorFromJsonRpcError({
+      code: 3,
+      message: "execution reverted",
+    });
+
+    assert.isTrue(ProviderError.isProviderError(error));
+    assert.equal(error.code, 3);
+    assert.equal(error.message, "execution reverted");
+  });
+
+  it("should keep the message and data of the error", function () {
+    const data = { data: "0x08c379a0", transactionHash: "0x1234" };
+    const error = providerErrorFromJsonRpcError({
+      code: InvalidArgumentsError.CODE,
+      message: "invalid params",
+      data,
+    });
+
+    assert.equal(error.message, "invalid params");
+    assert.deepEqual(error.data, data);
+  });
+});
@@ -4,6 +4,7 @@ import { BackwardsCompatibilityProviderAdapter } from "../../../../src/internal/
 import { JsonRpcServer } from "../../../../src/internal/hardhat-network/jsonrpc/server";
 import {
   ForkConfig,
+  GenesisAllocAccount,
   MempoolOrder,
 } from "../../../../src/internal/hardhat-network/provider/node-types";
 import { createHardhatNetworkProvider } from "../../../../src/internal/hardhat-network/provider/provider";
@@ -56,6 +57,8 @@ export interface UseProviderOptions {
   coinbase?: string;
   chains?: HardhatNetworkChainsConfig;
   forkBlockNumber?: number;
+  deterministicDeploymentProxy?: boolean;
+  genesisAlloc?: GenesisAllocAccount[];
 }
 
 export function useProvider({
@@ -74,6 +77,8 @@ export function useProvider({
   mempool = DEFAULT_MEMPOOL_CONFIG,
   coinbase,
   chains = defaultHardhatNetworkParams.chains,
+  deterministicDeploymentProxy = false,
+  genesisAlloc,
 }: UseProviderOptions = {}) {
   beforeEach("Initialize provider", async function () {
     this.logger = new FakeModulesLogger();
@@ -102,6 +107,8 @@ export function useProvider({
         allowBlocksWithSameTimestamp,
         enableTransientStorage: false,
         enableRip7212: false,
+        deterministicDeploymentProxy,
+        genesisAlloc,
       },
       {
         enabled: loggerEnabled,
@@ -0,0 +1,29 @@
+import { assert } from "chai";
+
+import { RateLimiter } from "../../../../src/internal/hardhat-network/jsonrpc/rate-limiter";
+
+describe("RateLimiter", function () {
+  it("should limit the requests of each key within a window", function () {
+    const limiter = new RateLimiter<string>({ maxRequests: 2, windowMs: 1000 });
+
+    assert.isTrue(limiter.tryAcquire("a", 0));
+    assert.isTrue(limiter.tryAcquire("a", 10));
+    assert.isFalse(limiter.tryAcquire("a", 20));
+    assert.isTrue(limiter.tryAcquire("b", 20));
+
+    assert.isTrue(limiter.tryAcquire("a", 1000));
+  });
+
+  it("should remove the windows of keys that stopped sending requests", function () {
+    const limiter = new RateLimiter<string>({ maxRequests: 2, windowMs: 1000 });
+
+    for (let i = 0; i < 100; i++) {
+      limiter.tryAcquire(`client-${i}`, 0);
+    }
+
+    limiter.tryAcquire("other-client", 2000);
+
+    const windows: Map<string, unknown> = (limiter as any)._windows;
+    assert.deepEqual([...windows.keys()], ["other-client"]);
+  });
+});
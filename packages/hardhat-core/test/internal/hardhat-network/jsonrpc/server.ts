@@ -0,0 +1,524 @@
+import { assert } from "chai";
+import { createHmac } from "crypto";
+import { request } from "undici";
+
+import { ERRORS } from "../../../../src/internal/core/errors-list";
+import {
+  InternalError,
+  LimitExceededError,
+  MethodNotEnabledError,
+  ResourceUnavailableError,
+  UnauthorizedError,
+} from "../../../../src/internal/core/providers/errors";
+import { JsonRpcAuthenticator } from "../../../../src/internal/hardhat-network/jsonrpc/auth";
+import {
+  JsonRpcServer,
+  JsonRpcServerConfig,
+} from "../../../../src/internal/hardhat-network/jsonrpc/server";
+import { expectHardhatError } from "../../../helpers/errors";
+import { MockedProvider } from "../../core/providers/mocks";
+
+async function sendRawRequest(
+  serverInfo: { address: string; port: number },
+  body: string,
+  headers: Record<string, string> = {}
+): Promise<any> {
+  const response = await request(
+    `http://${serverInfo.address}:${serverInfo.port}/`,
+    {
+      method: "POST",
+      body,
+      headers: { "Content-Type": "application/json", ...headers },
+    }
+  );
+
+  return response.body.json();
+}
+
+function makeJwt(secret: string, iat: number): string {
+  const encode = (value: object) =>
+    Buffer.from(JSON.stringify(value)).toString("base64url");
+
+  const header = encode({ alg: "HS256", typ: "JWT" });
+  const payload = encode({ iat });
+  const signature = createHmac("sha256", Buffer.from(secret, "hex"))
+    .update(`${header}.${payload}`)
+    .digest("base64url");
+
+  return `${header}.${payload}.${signature}`;
+}
+
+function jsonRpcRequest(method: string, id = 1) {
+  return { jsonrpc: "2.0", id, method, params: [] };
+}
+
+describe("JsonRpcServer", function () {
+  let provider: MockedProvider;
+  let server: JsonRpcServer;
+  let serverInfo: { address: string; port: number };
+  let isServerClosed: boolean;
+
+  async function startServer(
+    config: Omit<JsonRpcServerConfig, "hostname" | "port" | "provider">
+  ) {
+    server = new JsonRpcServer({
+      hostname: "127.0.0.1",
+      port: 0,
+      provider,
+      ...config,
+    });
+    serverInfo = await server.listen();
+    isServerClosed = false;
+  }
+
+  beforeEach(function () {
+    provider = new MockedProvider();
+    provider.setReturnValue("eth_blockNumber", "0x1");
+  });
+
+  afterEach(async function () {
+    if (!isServerClosed) {
+      await server.close();
+    }
+  });
+
+  describe("limits", function () {
+    it("should reject request bodies larger than maxRequestBodySize", async function () {
+      await startServer({ maxRequestBodySize: 100 });
+
+      const response = await sendRawRequest(
+        serverInfo,
+        JSON.stringify({
+          ...jsonRpcRequest("eth_blockNumber"),
+          params: ["a".repeat(200)],
+        })
+      );
+
+      assert.equal(response.error.code, LimitExceededError.CODE);
+      assert.equal(provider.getTotalNumberOfCalls(), 0);
+    });
+
+    it("should accept request bodies within maxRequestBodySize", async function () {
+      await startServer({ maxRequestBodySize: 1000 });
+
+      const response = await sendRawRequest(
+        serverInfo,
+        JSON.stringify(jsonRpcRequest("eth_blockNumber"))
+      );
+
+      assert.equal(response.result, "0x1");
+    });
+
+    it("should reject batches larger than maxBatchSize", async function () {
+      await startServer({ maxBatchSize: 2 });
+
+      const response = await sendRawRequest(
+        serverInfo,
+        JSON.stringify(
+          [1, 2, 3].map((id) => jsonRpcRequest("eth_blockNumber", id))
+        )
+      );
+
+      assert.equal(response.error.code, LimitExceededError.CODE);
+      assert.equal(provider.getTotalNumberOfCalls(), 0);
+    });
+
+    it("should accept batches within maxBatchSize", async function () {
+      await startServer({ maxBatchSize: 2 });
+
+      const response = await sendRawRequest(
+        serverInfo,
+        JSON.stringify(
+          [1, 2].map((id) => jsonRpcRequest("eth_blockNumber", id))
+        )
+      );
+
+      assert.deepEqual(response.map((r: any) => r.result), ["0x1", "0x1"]);
+    });
+
+    it("should reject requests over the rate limit", async function () {
+      await startServer({ rateLimit: { maxRequests: 2, windowMs: 60_000 } });
+
+      const body = JSON.stringify(jsonRpcRequest("eth_blockNumber"));
+
+      assert.equal((await sendRawRequest(serverInfo, body)).result, "0x1");
+      assert.equal((await sendRawRequest(serverInfo, body)).result, "0x1");
+
+      const response = await sendRawRequest(serverInfo, body);
+      assert.equal(response.error.code, LimitExceededError.CODE);
+      assert.equal(provider.getNumberOfCalls("eth_blockNumber"), 2);
+    });
+
+    it("should rate limit by the forwarded address behind a trusted proxy", async function () {
+      await startServer({
+        rateLimit: { maxRequests: 1, windowMs: 60_000, trustProxy: true },
+      });
+
+      const body = JSON.stringify(jsonRpcRequest("eth_blockNumber"));
+      const sendFrom = (address: string) =>
+        sendRawRequest(serverInfo, body, {
+          "X-Forwarded-For": `203.0.113.1, ${address}`,
+        });
+
+      assert.equal((await sendFrom("198.51.100.1")).result, "0x1");
+      assert.equal((await sendFrom("198.51.100.2")).result, "0x1");
+
+      const response = await sendFrom("198.51.100.1");
+      assert.equal(response.error.code, LimitExceededError.CODE);
+    });
+
+    it("should ignore X-Forwarded-For unless the proxy is trusted", async function () {
+      await startServer({ rateLimit: { maxRequests: 1, windowMs: 60_000 } });
+
+      const body = JSON.stringify(jsonRpcRequest("eth_blockNumber"));
+
+      await sendRawRequest(serverInfo, body, {
+        "X-Forwarded-For": "198.51.100.1",
+      });
+      const response = await sendRawRequest(serverInfo, body, {
+        "X-Forwarded-For": "198.51.100.2",
+      });
+
+      assert.equal(response.error.code, LimitExceededError.CODE);
+    });
+  });
+
+  describe("auth", function () {
+    const jwtSecret = "ab".repeat(32);
+
+    beforeEach(async function () {
+      provider.setReturnValue("hardhat_setBalance", true);
+
+      await startServer({
+        auth: {
+          jwtSecret,
+          apiKeys: [
+            { key: "admin-key" },
+            { key: "eth-key", namespaces: ["eth"] },
+          ],
+          publicNamespaces: ["net"],
+        },
+      });
+    });
+
+    async function callWithToken(method: string, token?: string) {
+      return sendRawRequest(
+        serverInfo,
+        JSON.stringify(jsonRpcRequest(method)),
+        token === undefined ? {} : { Authorization: `Bearer ${token}` }
+      );
+    }
+
+    it("should only allow public namespaces without credentials", async function () {
+      provider.setReturnValue("net_version", "31337");
+
+      assert.equal((await callWithToken("net_version")).result, "31337");
+      assert.equal(
+        (await callWithToken("eth_blockNumber")).error.code,
+        UnauthorizedError.CODE
+      );
+    });
+
+    it("should restrict API keys to their namespaces", async function () {
+      assert.equal(
+        (await callWithToken("eth_blockNumber", "eth-key")).result,
+        "0x1"
+      );
+      assert.equal(
+        (await callWithToken("hardhat_setBalance", "eth-key")).error.code,
+        UnauthorizedError.CODE
+      );
+      assert.equal(provider.getNumberOfCalls("hardhat_setBalance"), 0);
+    });
+
+    it("should allow every method to API keys without namespaces", async function () {
+      assert.isTrue(
+        (await callWithToken("hardhat_setBalance", "admin-key")).result
+      );
+    });
+
+    it("should reject unknown API keys", async function () {
+      assert.equal(
+        (await callWithToken("eth_blockNumber", "other-key")).error.code,
+        UnauthorizedError.CODE
+      );
+    });
+
+    it("should allow every method to valid JWTs", async function () {
+      const token = makeJwt(jwtSecret, Math.floor(Date.now() / 1000));
+
+      assert.isTrue((await callWithToken("hardhat_setBalance", token)).result);
+    });
+
+    it("should reject JWTs with a stale iat", async function () {
+      const token = makeJwt(jwtSecret, Math.floor(Date.now() / 1000) - 120);
+
+      assert.equal(
+        (await callWithToken("hardhat_setBalance", token)).error.code,
+        UnauthorizedError.CODE
+      );
+    });
+
+    it("should reject JWT secrets that aren't 32 hex-encoded bytes", function () {
+      const invalidSecrets = ["mysecret", "ab".repeat(16), "zz".repeat(32)];
+
+      for (const invalidSecret of invalidSecrets) {
+        expectHardhatError(
+          () => new JsonRpcAuthenticator({ jwtSecret: invalidSecret }),
+          ERRORS.NETWORK.INVALID_JWT_SECRET
+        );
+      }
+    });
+
+    it("should accept 0x-prefixed JWT secrets", function () {
+      assert.doesNotThrow(
+        () => new JsonRpcAuthenticator({ jwtSecret: `0x${jwtSecret}` })
+      );
+    });
+
+    it("should reject JWTs signed with another secret", async function () {
+      const token = makeJwt("cd".repeat(32), Math.floor(Date.now() / 1000));
+
+      assert.equal(
+        (await callWithToken("hardhat_setBalance", token)).error.code,
+        UnauthorizedError.CODE
+      );
+    });
+  });
+
+  describe("methods", function () {
+    beforeEach(function () {
+      provider.setReturnValue("net_version", "31337");
+      provider.setReturnValue("hardhat_mine", true);
+      provider.setReturnValue("hardhat_setBalance", true);
+      provider.setReturnValue("evm_mine", "0x0");
+    });
+
+    async function call(method: string) {
+      return sendRawRequest(serverInfo, JSON.stringify(jsonRpcRequest(method)));
+    }
+
+    it("should reject disabled namespaces and methods", async function () {
+      await startServer({
+        methods: { disabled: ["hardhat", "evm_mine"] },
+      });
+
+      assert.equal(
+        (await call("hardhat_setBalance")).error.code,
+        MethodNotEnabledError.CODE
+      );
+      assert.equal(
+        (await call("evm_mine")).error.code,
+        MethodNotEnabledError.CODE
+      );
+      assert.equal((await call("eth_blockNumber")).result, "0x1");
+      assert.equal(provider.getNumberOfCalls("hardhat_setBalance"), 0);
+      assert.equal(provider.getNumberOfCalls("evm_mine"), 0);
+    });
+
+    it("should only allow enabled namespaces and methods", async function () {
+      await startServer({
+        methods: { enabled: ["eth", "hardhat_mine"] },
+      });
+
+      assert.equal((await call("eth_blockNumber")).result, "0x1");
+      assert.isTrue((await call("hardhat_mine")).result);
+      assert.equal(
+        (await call("hardhat_setBalance")).error.code,
+        MethodNotEnabledError.CODE
+      );
+      assert.equal(
+        (await call("net_version")).error.code,
+        MethodNotEnabledError.CODE
+      );
+    });
+
+    it("should let method entries take precedence over namespaces", async function () {
+      await startServer({
+        methods: { disabled: ["hardhat"], enabled: ["hardhat_mine"] },
+      });
+
+      assert.isTrue((await call("hardhat_mine")).result);
+      assert.equal((await call("net_version")).result, "31337");
+      assert.equal(
+        (await call("hardhat_setBalance")).error.code,
+        MethodNotEnabledError.CODE
+      );
+    });
+  });
+
+  describe("reverse proxy support", function () {
+    function url(path: string) {
+      return `http://${serverInfo.address}:${serverInfo.port}${path}`;
+    }
+
+    it("should only allow the configured CORS origins", async function () {
+      await startServer({ corsOrigins: ["https://app.example.com"] });
+
+      const allowed = await request(url("/"), {
+        method: "OPTIONS",
+        headers: { Origin: "https://app.example.com" },
+      });
+      await allowed.body.dump();
+      assert.equal(
+        allowed.headers["access-control-allow-origin"],
+        "https://app.example.com"
+      );
+
+      const denied = await request(url("/"), {
+        method: "OPTIONS",
+        headers: { Origin: "https://other.example.com" },
+      });
+      await denied.body.dump();
+      assert.isUndefined(denied.headers["access-control-allow-origin"]);
+    });
+
+    it("should serve JSON-RPC under the path prefix", async function () {
+      await startServer({ pathPrefix: "/rpc/" });
+
+      const response = await request(url("/rpc"), {
+        method: "POST",
+        body: JSON.stringify(jsonRpcRequest("eth_blockNumber")),
+        headers: { "Content-Type": "application/json" },
+      });
+      assert.equal((await response.body.json()).result, "0x1");
+
+      const outside = await request(url("/"), {
+        method: "POST",
+        body: JSON.stringify(jsonRpcRequest("eth_blockNumber")),
+        headers: { "Content-Type": "application/json" },
+      });
+      await outside.body.dump();
+      assert.equal(outside.statusCode, 404);
+      assert.equal(provider.getNumberOfCalls("eth_blockNumber"), 1);
+    });
+
+    it("should return a provider that sends requests under the path prefix", async function () {
+      await startServer({ pathPrefix: "rpc/v1/" });
+
+      const serverProvider = server.getProvider();
+      assert.equal(
+        await serverProvider.request({ method: "eth_blockNumber" }),
+        "0x1"
+      );
+      assert.equal(provider.getNumberOfCalls("eth_blockNumber"), 1);
+    });
+
+    it("should serve the health and readiness endpoints", async function () {
+      await startServer({ pathPrefix: "/rpc" });
+      provider.setReturnValue("eth_chainId", "0x7a69");
+
+      const health = await request(url("/rpc/health"));
+      assert.equal(health.statusCode, 200);
+      assert.deepEqual(await health.body.json(), { status: "ok" });
+
+      const ready = await request(url("/rpc/ready"));
+      assert.equal(ready.statusCode, 200);
+      assert.deepEqual(await ready.body.json(), { status: "ready" });
+    });
+  });
+
+  describe("shutdown", function () {
+    it("should wait for in-flight requests before running the hook", async function () {
+      let resolveBlockNumber: (value: string) => void = () => {};
+      provider.setReturnValue(
+        "eth_blockNumber",
+        () =>
+          new Promise((resolve) => {
+            resolveBlockNumber = resolve;
+          })
+      );
+
+      let onShutdownCalls = 0;
+      await startServer({
+        onShutdown: async () => {
+          onShutdownCalls += 1;
+        },
+      });
+
+      const inFlight = sendRawRequest(
+        serverInfo,
+        JSON.stringify(jsonRpcRequest("eth_blockNumber"))
+      );
+
+      // wait until the provider received the request
+      while (provider.getNumberOfCalls("eth_blockNumber") === 0) {
+        await new Promise((resolve) => setTimeout(resolve, 10));
+      }
+
+      const shutdown = server.shutdown();
+
+      const rejected = await sendRawRequest(
+        serverInfo,
+        JSON.stringify(jsonRpcRequest("eth_blockNumber"))
+      );
+      assert.equal(rejected.error.code, ResourceUnavailableError.CODE);
+      assert.equal(onShutdownCalls, 0);
+
+      resolveBlockNumber("0x1");
+
+      assert.equal((await inFlight).result, "0x1");
+      await shutdown;
+      isServerClosed = true;
+
+      assert.equal(onShutdownCalls, 1);
+      assert.equal(provider.getNumberOfCalls("eth_blockNumber"), 1);
+    });
+
+    it("should answer pending long-polling requests instead of waiting for them", async function () {
+      // a long poll that never gets any changes
+      provider.setReturnValue(
+        "hardhat_waitForFilterChanges",
+        () => new Promise(() => {})
+      );
+
+      await startServer({});
+
+      const longPoll = sendRawRequest(
+        serverInfo,
+        JSON.stringify(jsonRpcRequest("hardhat_waitForFilterChanges"))
+      );
+
+      // wait until the provider received the request
+      while (provider.getNumberOfCalls("hardhat_waitForFilterChanges") === 0) {
+        await new Promise((resolve) => setTimeout(resolve, 10));
+      }
+
+      await server.shutdown();
+      isServerClosed = true;
+
+      assert.deepEqual((await longPoll).result, []);
+    });
+  });
+
+  describe("fault injection", function () {
+    async function call(method: string) {
+      return sendRawRequest(serverInfo, JSON.stringify(jsonRpcRequest(method)));
+    }
+
+    it("should fail requests according to the error rate", async function () {
+      provider.setReturnValue("net_version", "31337");
+
+      await startServer({
+        faultInjection: { methods: { eth_blockNumber: { errorRate: 1 } } },
+      });
+
+      assert.equal(
+        (await call("eth_blockNumber")).error.code,
+        InternalError.CODE
+      );
+      assert.equal((await call("net_version")).result, "31337");
+      assert.equal(provider.getNumberOfCalls("eth_blockNumber"), 0);
+    });
+
+    it("should add latency to the methods without their own entry", async function () {
+      await startServer({
+        faultInjection: { methods: { "*": { latencyMs: 200 } } },
+      });
+
+      const start = Date.now();
+      assert.equal((await call("eth_blockNumber")).result, "0x1");
+      assert.isAtLeast(Date.now() - start, 200);
+    });
+  });
+});
@@ -0,0 +1,52 @@
+import { assert } from "chai";
+
+import { PROVIDERS } from "../helpers/providers";
+
+describe("Hardhat Network provider", function () {
+  PROVIDERS.forEach(({ name, useProvider, isFork }) => {
+    if (isFork) {
+      return;
+    }
+
+    describe(`${name} provider`, function () {
+      describe("contract address methods", function () {
+        useProvider();
+
+        it("should return the CREATE address of an account and nonce", async function () {
+          const from = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0";
+
+          assert.equal(
+            await this.provider.send("hardhat_getCreateAddress", [from, "0x0"]),
+            "0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"
+          );
+          assert.equal(
+            await this.provider.send("hardhat_getCreateAddress", [from, "0x1"]),
+            "0x343c43a37d37dff08ae8c4a11544c718abb4fcf8"
+          );
+        });
+
+        it("should use the next nonce of the account by default", async function () {
+          const from = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0";
+          await this.provider.send("hardhat_setNonce", [from, "0x1"]);
+
+          assert.equal(
+            await this.provider.send("hardhat_getCreateAddress", [from]),
+            "0x343c43a37d37dff08ae8c4a11544c718abb4fcf8"
+          );
+        });
+
+        it("should return the CREATE2 address of a deployer, salt and init code", async function () {
+          // The first example of EIP-1014
+          assert.equal(
+            await this.provider.send("hardhat_getCreate2Address", [
+              "0x0000000000000000000000000000000000000000",
+              `0x${"00".repeat(32)}`,
+              "0x00",
+            ]),
+            "0x4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38"
+          );
+        });
+      });
+    });
+  });
+});
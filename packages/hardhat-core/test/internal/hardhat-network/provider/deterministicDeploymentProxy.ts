@@ -0,0 +1,94 @@
+import { assert } from "chai";
+
+import {
+  DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS,
+  DETERMINISTIC_DEPLOYMENT_PROXY_CODE,
+} from "../../../../src/internal/hardhat-network/provider/utils/deterministicDeploymentProxy";
+import { DEFAULT_ACCOUNTS_ADDRESSES, PROVIDERS } from "../helpers/providers";
+
+describe("Hardhat Network provider", function () {
+  PROVIDERS.forEach(({ name, useProvider, isFork }) => {
+    if (isFork) {
+      return;
+    }
+
+    describe(`${name} provider`, function () {
+      describe("deterministic deployment proxy", function () {
+        // A contract whose runtime code returns 42
+        const initCode = "0x69602a60005260206000f3600052600a6016f3";
+        const runtimeCode = "0x602a60005260206000f3";
+        const salt = `0x${"00".repeat(32)}`;
+        const expectedAddress = "0x07d382a51308c64505e78a88e7872dcfadd6a3bf";
+
+        describe("when enabled", function () {
+          useProvider({ deterministicDeploymentProxy: true });
+
+          it("should have the proxy deployed", async function () {
+            assert.equal(
+              await this.provider.send("eth_getCode", [
+                DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS,
+                "latest",
+              ]),
+              DETERMINISTIC_DEPLOYMENT_PROXY_CODE
+            );
+          });
+
+          it("should deploy contracts to their CREATE2 address", async function () {
+            const [sender] = DEFAULT_ACCOUNTS_ADDRESSES;
+
+            assert.equal(
+              await this.provider.send("hardhat_getCreate2Address", [
+                DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS,
+                salt,
+                initCode,
+              ]),
+              expectedAddress
+            );
+
+            await this.provider.send("eth_sendTransaction", [
+              {
+                from: sender,
+                to: DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS,
+                data: `${salt}${initCode.slice(2)}`,
+              },
+            ]);
+
+            assert.equal(
+              await this.provider.send("eth_getCode", [
+                expectedAddress,
+                "latest",
+              ]),
+              runtimeCode
+            );
+          });
+
+          it("should deploy the proxy again after hardhat_reset", async function () {
+            await this.provider.send("hardhat_reset");
+
+            assert.equal(
+              await this.provider.send("eth_getCode", [
+                DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS,
+                "latest",
+              ]),
+              DETERMINISTIC_DEPLOYMENT_PROXY_CODE
+            );
+          });
+        });
+
+        describe("when disabled", function () {
+          useProvider();
+
+          it("shouldn't have the proxy deployed", async function () {
+            assert.equal(
+              await this.provider.send("eth_getCode", [
+                DETERMINISTIC_DEPLOYMENT_PROXY_ADDRESS,
+                "latest",
+              ]),
+              "0x"
+            );
+          });
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,86 @@
+import { assert } from "chai";
+
+import { rpcQuantityToNumber } from "../../../../src/internal/core/jsonrpc/types/base-types";
+import { BITFINEX_WALLET_ADDRESS } from "../helpers/constants";
+import { PROVIDERS } from "../helpers/providers";
+
+describe("Hardhat Network provider", function () {
+  PROVIDERS.forEach(({ name, useProvider, isFork }) => {
+    describe(`${name} provider`, function () {
+      if (isFork) {
+        describe("genesis alloc", function () {
+          useProvider({
+            genesisAlloc: [
+              {
+                address: BITFINEX_WALLET_ADDRESS.toString(),
+                nonce: "0x1",
+                storage: {},
+              },
+            ],
+          });
+
+          it("should keep the nonces that are higher than the alloc's", async function () {
+            const nonce = await this.provider.send("eth_getTransactionCount", [
+              BITFINEX_WALLET_ADDRESS.toString(),
+              "latest",
+            ]);
+
+            assert.isAbove(rpcQuantityToNumber(nonce), 1);
+          });
+        });
+
+        return;
+      }
+
+      describe("genesis alloc", function () {
+        const address = "0x0000000000000000000000000000000000000bad";
+
+        // An account of the alloc that doesn't change the nonce
+        const zeroNonceAddress = "0x0000000000000000000000000000000000000bee";
+
+        useProvider({
+          genesisAlloc: [
+            { address, balance: "0x100", nonce: "0x5", storage: {} },
+            { address: zeroNonceAddress, nonce: "0x0", storage: {} },
+          ],
+        });
+
+        it("should set up the accounts of the alloc", async function () {
+          assert.equal(
+            await this.provider.send("eth_getBalance", [address, "latest"]),
+            "0x100"
+          );
+          assert.equal(
+            await this.provider.send("eth_getTransactionCount", [
+              address,
+              "latest",
+            ]),
+            "0x5"
+          );
+        });
+
+        it("should set up the accounts of the alloc again after hardhat_reset", async function () {
+          await this.provider.send("hardhat_reset");
+
+          assert.equal(
+            await this.provider.send("eth_getTransactionCount", [
+              address,
+              "latest",
+            ]),
+            "0x5"
+          );
+        });
+
+        it("should skip the nonces that aren't higher than the account's", async function () {
+          assert.equal(
+            await this.provider.send("eth_getTransactionCount", [
+              zeroNonceAddress,
+              "latest",
+            ]),
+            "0x0"
+          );
+        });
+      });
+    });
+  });
+});
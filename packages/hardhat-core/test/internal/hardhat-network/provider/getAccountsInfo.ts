@@ -0,0 +1,33 @@
+import { assert } from "chai";
+
+import { PROVIDERS } from "../helpers/providers";
+
+describe("Hardhat Network provider", function () {
+  PROVIDERS.forEach(({ name, useProvider, isFork }) => {
+    if (isFork) {
+      return;
+    }
+
+    describe(`${name} provider`, function () {
+      describe("hardhat_getAccountsInfo", function () {
+        useProvider();
+
+        it("should not be affected by changes to the returned list", async function () {
+          const accountsInfo = await this.provider.send(
+            "hardhat_getAccountsInfo"
+          );
+          const address = accountsInfo[0].address;
+          accountsInfo[0].address =
+            "0x0000000000000000000000000000000000000bad";
+          accountsInfo.pop();
+
+          const newAccountsInfo = await this.provider.send(
+            "hardhat_getAccountsInfo"
+          );
+          assert.lengthOf(newAccountsInfo, accountsInfo.length + 1);
+          assert.equal(newAccountsInfo[0].address, address);
+        });
+      });
+    });
+  });
+});
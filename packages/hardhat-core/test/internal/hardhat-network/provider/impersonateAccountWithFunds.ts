@@ -0,0 +1,135 @@
+import { assert } from "chai";
+
+import { HARDHAT_NETWORK_IMPERSONATE_ACCOUNT_EVENT } from "../../../../src/internal/constants";
+import { numberToRpcQuantity } from "../../../../src/internal/core/jsonrpc/types/base-types";
+import { DEFAULT_ACCOUNTS_ADDRESSES, PROVIDERS } from "../helpers/providers";
+
+describe("Hardhat Network provider", function () {
+  PROVIDERS.forEach(({ name, useProvider, isFork }) => {
+    if (isFork) {
+      return;
+    }
+
+    describe(`${name} provider`, function () {
+      describe("hardhat_impersonateAccountWithFunds", function () {
+        useProvider();
+
+        const impersonatedAddress =
+          "0x0000000000000000000000000000000000000bad";
+
+        it("should restore the original balance when the impersonation stops", async function () {
+          const [sender] = DEFAULT_ACCOUNTS_ADDRESSES;
+          await this.provider.send("eth_sendTransaction", [
+            {
+              from: sender,
+              to: impersonatedAddress,
+              value: numberToRpcQuantity(1),
+            },
+          ]);
+
+          const funds = numberToRpcQuantity(10n ** 18n);
+          assert.isTrue(
+            await this.provider.send("hardhat_impersonateAccountWithFunds", [
+              impersonatedAddress,
+              funds,
+            ])
+          );
+          assert.equal(
+            await this.provider.send("eth_getBalance", [impersonatedAddress]),
+            funds
+          );
+
+          // the account can send transactions while it's impersonated
+          await this.provider.send("eth_sendTransaction", [
+            { from: impersonatedAddress, to: sender },
+          ]);
+
+          await this.provider.send("hardhat_stopImpersonatingAccount", [
+            impersonatedAddress,
+          ]);
+          assert.equal(
+            await this.provider.send("eth_getBalance", [impersonatedAddress]),
+            numberToRpcQuantity(1)
+          );
+        });
+
+        it("should keep the balance from before the first call", async function () {
+          for (const funds of [10n ** 18n, 2n * 10n ** 18n]) {
+            await this.provider.send("hardhat_impersonateAccountWithFunds", [
+              impersonatedAddress,
+              numberToRpcQuantity(funds),
+            ]);
+          }
+
+          await this.provider.send("hardhat_stopImpersonatingAccount", [
+            impersonatedAddress,
+          ]);
+          assert.equal(
+            await this.provider.send("eth_getBalance", [impersonatedAddress]),
+            "0x0"
+          );
+        });
+
+        it("should not restore the balance after reverting to a snapshot from before the funding", async function () {
+          const snapshotId = await this.provider.send("evm_snapshot");
+
+          const [sender] = DEFAULT_ACCOUNTS_ADDRESSES;
+          await this.provider.send("eth_sendTransaction", [
+            {
+              from: sender,
+              to: impersonatedAddress,
+              value: numberToRpcQuantity(1),
+            },
+          ]);
+
+          await this.provider.send("hardhat_impersonateAccountWithFunds", [
+            impersonatedAddress,
+            numberToRpcQuantity(10n ** 18n),
+          ]);
+          await this.provider.send("evm_revert", [snapshotId]);
+
+          await this.provider.send("hardhat_stopImpersonatingAccount", [
+            impersonatedAddress,
+          ]);
+          assert.equal(
+            await this.provider.send("eth_getBalance", [impersonatedAddress]),
+            "0x0"
+          );
+        });
+
+        it("should restore the balance after reverting to a snapshot from after the funding", async function () {
+          await this.provider.send("hardhat_impersonateAccountWithFunds", [
+            impersonatedAddress,
+            numberToRpcQuantity(10n ** 18n),
+          ]);
+
+          const snapshotId = await this.provider.send("evm_snapshot");
+          await this.provider.send("evm_revert", [snapshotId]);
+
+          await this.provider.send("hardhat_stopImpersonatingAccount", [
+            impersonatedAddress,
+          ]);
+          assert.equal(
+            await this.provider.send("eth_getBalance", [impersonatedAddress]),
+            "0x0"
+          );
+        });
+
+        it("should emit the impersonation event", async function () {
+          const events: unknown[] = [];
+          this.hardhatNetworkProvider.on(
+            HARDHAT_NETWORK_IMPERSONATE_ACCOUNT_EVENT,
+            (address: unknown) => events.push(address)
+          );
+
+          await this.provider.send("hardhat_impersonateAccountWithFunds", [
+            impersonatedAddress,
+            numberToRpcQuantity(1),
+          ]);
+
+          assert.deepEqual(events, [impersonatedAddress]);
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,62 @@
+import { assert } from "chai";
+
+import {
+  HARDHAT_NETWORK_IMPERSONATE_ACCOUNT_EVENT,
+  HARDHAT_NETWORK_STOP_IMPERSONATING_ACCOUNT_EVENT,
+} from "../../../../src/internal/constants";
+import { EIP1193Provider } from "../../../../src/types";
+import { PROVIDERS } from "../helpers/providers";
+
+describe("Hardhat Network provider", function () {
+  PROVIDERS.forEach(({ name, useProvider, isFork }) => {
+    if (isFork) {
+      return;
+    }
+
+    describe(`${name} provider`, function () {
+      describe("impersonation events", function () {
+        useProvider();
+
+        const impersonatedAddress =
+          "0x0000000000000000000000000000000000000bad";
+
+        function recordEvents(provider: EIP1193Provider, eventName: string) {
+          const events: unknown[] = [];
+          provider.on(eventName, (address: unknown) => events.push(address));
+          return events;
+        }
+
+        it("should emit an event when an account is impersonated", async function () {
+          const events = recordEvents(
+            this.hardhatNetworkProvider,
+            HARDHAT_NETWORK_IMPERSONATE_ACCOUNT_EVENT
+          );
+
+          await this.provider.send("hardhat_impersonateAccount", [
+            impersonatedAddress,
+          ]);
+
+          assert.deepEqual(events, [impersonatedAddress]);
+        });
+
+        it("should emit an event when an impersonation stops", async function () {
+          const events = recordEvents(
+            this.hardhatNetworkProvider,
+            HARDHAT_NETWORK_STOP_IMPERSONATING_ACCOUNT_EVENT
+          );
+
+          await this.provider.send("hardhat_impersonateAccount", [
+            impersonatedAddress,
+          ]);
+          assert.isEmpty(events);
+
+          await this.provider.send("hardhat_stopImpersonatingAccount", [
+            impersonatedAddress,
+          ]);
+
+          assert.deepEqual(events, [impersonatedAddress]);
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,259 @@
+import { assert } from "chai";
+
+import {
+  numberToRpcQuantity,
+  rpcQuantityToNumber,
+} from "../../../../src/internal/core/jsonrpc/types/base-types";
+import { MethodNotSupportedError } from "../../../../src/internal/core/providers/errors";
+import { EdrProviderWrapper } from "../../../../src/internal/hardhat-network/provider/provider";
+import { DEFAULT_MEMPOOL_CONFIG, PROVIDERS } from "../helpers/providers";
+import { sleep } from "../helpers/sleep";
+
+describe("Hardhat Network provider", function () {
+  PROVIDERS.forEach(({ name, useProvider, isFork }) => {
+    if (isFork) {
+      return;
+    }
+
+    describe(`${name} provider`, function () {
+      describe("geth miner methods", function () {
+        describe("with automine", function () {
+          useProvider();
+
+          it("should set the coinbase with miner_setEtherbase", async function () {
+            const coinbase = "0x0000000000000000000000000000000000000bad";
+
+            await this.provider.send("miner_setEtherbase", [coinbase]);
+
+            assert.equal(await this.provider.send("eth_coinbase"), coinbase);
+          });
+
+          it("should set the block gas limit with miner_setGasLimit", async function () {
+            await this.provider.send("miner_setGasLimit", [
+              numberToRpcQuantity(12_345_678),
+            ]);
+            await this.provider.send("evm_mine");
+
+            const block = await this.provider.send("eth_getBlockByNumber", [
+              "latest",
+              false,
+            ]);
+            assert.equal(rpcQuantityToNumber(block.gasLimit), 12_345_678);
+          });
+
+          it("should reject miner_setGasPrice when EIP-1559 is active", async function () {
+            try {
+              await this.provider.send("miner_setGasPrice", [
+                numberToRpcQuantity(10),
+              ]);
+            } catch (error: any) {
+              assert.equal(error.code, MethodNotSupportedError.CODE);
+              assert.include(error.message, "miner_setGasPrice");
+              return;
+            }
+
+            assert.fail("miner_setGasPrice should have been rejected");
+          });
+        });
+
+        describe("before London", function () {
+          useProvider({ hardfork: "berlin" });
+
+          it("should set the minimum gas price with miner_setGasPrice", async function () {
+            assert.isTrue(
+              await this.provider.send("miner_setGasPrice", [
+                numberToRpcQuantity(10),
+              ])
+            );
+          });
+        });
+
+        describe("with interval mining", function () {
+          useProvider({
+            mining: {
+              auto: false,
+              interval: 50,
+              mempool: DEFAULT_MEMPOOL_CONFIG,
+            },
+          });
+
+          async function getBlockNumber(provider: any): Promise<number> {
+            return rpcQuantityToNumber(await provider.send("eth_blockNumber"));
+          }
+
+          it("should restore interval mining after miner_stop and miner_start", async function () {
+            await this.provider.send("miner_stop");
+            const stoppedBlockNumber = await getBlockNumber(this.provider);
+
+            await sleep(200);
+            assert.equal(
+              await getBlockNumber(this.provider),
+              stoppedBlockNumber
+            );
+
+            await this.provider.send("miner_start");
+
+            await sleep(200);
+            assert.isAbove(
+              await getBlockNumber(this.provider),
+              stoppedBlockNumber
+            );
+          });
+
+          it("should keep the interval mining config if miner_stop is called twice", async function () {
+            await this.provider.send("miner_stop");
+            await this.provider.send("miner_stop");
+            const stoppedBlockNumber = await getBlockNumber(this.provider);
+
+            await this.provider.send("miner_start");
+
+            await sleep(200);
+            assert.isAbove(
+              await getBlockNumber(this.provider),
+              stoppedBlockNumber
+            );
+          });
+        });
+      });
+
+      describe("snapshots", function () {
+        useProvider();
+
+        it("should list the snapshots with their labels", async function () {
+          const firstId = await this.provider.send("evm_snapshot", ["first"]);
+          await this.provider.send("evm_mine");
+          const secondId = await this.provider.send("evm_snapshot");
+
+          const latestBlock = await this.provider.send("eth_getBlockByNumber", [
+            "latest",
+            false,
+          ]);
+
+          const snapshots = await this.provider.send("hardhat_listSnapshots");
+          assert.lengthOf(snapshots, 2);
+
+          assert.equal(snapshots[0].id, firstId);
+          assert.equal(snapshots[0].label, "first");
+          assert.equal(
+            rpcQuantityToNumber(snapshots[0].blockNumber),
+            rpcQuantityToNumber(latestBlock.number) - 1
+          );
+
+          assert.equal(snapshots[1].id, secondId);
+          assert.isUndefined(snapshots[1].label);
+          assert.equal(snapshots[1].blockNumber, latestBlock.number);
+          assert.equal(snapshots[1].timestamp, latestBlock.timestamp);
+        });
+
+        it("should remove the reverted snapshot and the ones taken after it", async function () {
+          const firstId = await this.provider.send("evm_snapshot", ["first"]);
+          const secondId = await this.provider.send("evm_snapshot", ["second"]);
+          await this.provider.send("evm_snapshot", ["third"]);
+
+          await this.provider.send("evm_revert", [secondId]);
+
+          const snapshots = await this.provider.send("hardhat_listSnapshots");
+          assert.deepEqual(
+            snapshots.map((snapshot: any) => snapshot.id),
+            [firstId]
+          );
+        });
+
+        it("should remove every snapshot on hardhat_reset", async function () {
+          await this.provider.send("evm_snapshot", ["first"]);
+          await this.provider.send("hardhat_reset");
+
+          assert.isEmpty(await this.provider.send("hardhat_listSnapshots"));
+        });
+
+        it("should not be affected by changes to the returned list", async function () {
+          await this.provider.send("evm_snapshot", ["first"]);
+
+          const snapshots = await this.provider.send("hardhat_listSnapshots");
+          snapshots[0].label = "changed";
+          snapshots.pop();
+
+          const [snapshot] = await this.provider.send("hardhat_listSnapshots");
+          assert.equal(snapshot.label, "first");
+        });
+      });
+
+      describe("hardhat_waitForFilterChanges", function () {
+        useProvider();
+
+        it("should return the changes once they arrive", async function () {
+          const filterId = await this.provider.send("eth_newBlockFilter");
+
+          const changesPromise = this.provider.send(
+            "hardhat_waitForFilterChanges",
+            [filterId, 10_000]
+          );
+
+          await sleep(200);
+          await this.provider.send("evm_mine");
+
+          const block = await this.provider.send("eth_getBlockByNumber", [
+            "latest",
+            false,
+          ]);
+          assert.deepEqual(await changesPromise, [block.hash]);
+        });
+
+        it("should return an empty array if the timeout expires", async function () {
+          const filterId = await this.provider.send("eth_newBlockFilter");
+
+          const start = Date.now();
+          const changes = await this.provider.send(
+            "hardhat_waitForFilterChanges",
+            [filterId, 300]
+          );
+
+          assert.deepEqual(changes, []);
+          assert.isAtLeast(Date.now() - start, 300);
+        });
+
+        it("should return null if the filter doesn't exist", async function () {
+          assert.isNull(
+            await this.provider.send("hardhat_waitForFilterChanges", ["0x123"])
+          );
+        });
+
+        it("should stop waiting when the provider is closed", async function () {
+          const filterId = await this.provider.send("eth_newBlockFilter");
+
+          const start = Date.now();
+          const changesPromise = this.provider.send(
+            "hardhat_waitForFilterChanges",
+            [filterId, 10_000]
+          );
+
+          await sleep(200);
+          (this.hardhatNetworkProvider as EdrProviderWrapper).close();
+
+          assert.deepEqual(await changesPromise, []);
+          assert.isBelow(Date.now() - start, 5_000);
+        });
+      });
+
+      describe("hardhat_getAccountsInfo", function () {
+        useProvider();
+
+        it("should not be affected by changes to the returned list", async function () {
+          const accountsInfo = await this.provider.send(
+            "hardhat_getAccountsInfo"
+          );
+          const address = accountsInfo[0].address;
+          accountsInfo[0].address =
+            "0x0000000000000000000000000000000000000bad";
+          accountsInfo.pop();
+
+          const newAccountsInfo = await this.provider.send(
+            "hardhat_getAccountsInfo"
+          );
+          assert.lengthOf(newAccountsInfo, accountsInfo.length + 1);
+          assert.equal(newAccountsInfo[0].address, address);
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,76 @@
+import { assert } from "chai";
+
+import { rpcQuantityToNumber } from "../../../../src/internal/core/jsonrpc/types/base-types";
+import { PROVIDERS } from "../helpers/providers";
+
+describe("Hardhat Network provider", function () {
+  PROVIDERS.forEach(({ name, useProvider, isFork }) => {
+    if (isFork) {
+      return;
+    }
+
+    describe(`${name} provider`, function () {
+      describe("snapshots", function () {
+        useProvider();
+
+        it("should list the snapshots with their labels", async function () {
+          const firstId = await this.provider.send("evm_snapshot", ["first"]);
+          await this.provider.send("evm_mine");
+          const secondId = await this.provider.send("evm_snapshot");
+
+          const latestBlock = await this.provider.send("eth_getBlockByNumber", [
+            "latest",
+            false,
+          ]);
+
+          const snapshots = await this.provider.send("hardhat_listSnapshots");
+          assert.lengthOf(snapshots, 2);
+
+          assert.equal(snapshots[0].id, firstId);
+          assert.equal(snapshots[0].label, "first");
+          assert.equal(
+            rpcQuantityToNumber(snapshots[0].blockNumber),
+            rpcQuantityToNumber(latestBlock.number) - 1
+          );
+
+          assert.equal(snapshots[1].id, secondId);
+          assert.isUndefined(snapshots[1].label);
+          assert.equal(snapshots[1].blockNumber, latestBlock.number);
+          assert.equal(snapshots[1].timestamp, latestBlock.timestamp);
+        });
+
+        it("should remove the reverted snapshot and the ones taken after it", async function () {
+          const firstId = await this.provider.send("evm_snapshot", ["first"]);
+          const secondId = await this.provider.send("evm_snapshot", ["second"]);
+          await this.provider.send("evm_snapshot", ["third"]);
+
+          await this.provider.send("evm_revert", [secondId]);
+
+          const snapshots = await this.provider.send("hardhat_listSnapshots");
+          assert.deepEqual(
+            snapshots.map((snapshot: any) => snapshot.id),
+            [firstId]
+          );
+        });
+
+        it("should remove every snapshot on hardhat_reset", async function () {
+          await this.provider.send("evm_snapshot", ["first"]);
+          await this.provider.send("hardhat_reset");
+
+          assert.isEmpty(await this.provider.send("hardhat_listSnapshots"));
+        });
+
+        it("should not be affected by changes to the returned list", async function () {
+          await this.provider.send("evm_snapshot", ["first"]);
+
+          const snapshots = await this.provider.send("hardhat_listSnapshots");
+          snapshots[0].label = "changed";
+          snapshots.pop();
+
+          const [snapshot] = await this.provider.send("hardhat_listSnapshots");
+          assert.equal(snapshot.label, "first");
+        });
+      });
+    });
+  });
+});
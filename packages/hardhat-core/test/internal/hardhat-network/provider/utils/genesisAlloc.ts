@@ -0,0 +1,77 @@
+import { assert } from "chai";
+
+import { parseGenesisAlloc } from "../../../../../src/internal/hardhat-network/provider/utils/genesisAlloc";
+
+describe("parseGenesisAlloc", () => {
+  it("parses the alloc of a geth genesis file", async () => {
+    const alloc = parseGenesisAlloc({
+      config: { chainId: 1337 },
+      alloc: {
+        "71562b71999873DB5b286dF957af199Ec94617F7": {
+          balance: "1000",
+          nonce: "0x2",
+        },
+        "0x0000000000000000000000000000000000000aAa": {
+          code: "0x6001",
+          storage: {
+            "0x01": "0x2a",
+            "2": "7",
+          },
+        },
+      },
+    });
+
+    assert.deepEqual(alloc, [
+      {
+        address: "0x71562b71999873db5b286df957af199ec94617f7",
+        balance: "0x3e8",
+        nonce: "0x2",
+        storage: {},
+      },
+      {
+        address: "0x0000000000000000000000000000000000000aaa",
+        code: "0x6001",
+        storage: {
+          "0x1":
+            "0x000000000000000000000000000000000000000000000000000000000000002a",
+          "0x2":
+            "0x0000000000000000000000000000000000000000000000000000000000000007",
+        },
+      },
+    ]);
+  });
+
+  it("accepts a file with only the alloc", async () => {
+    const alloc = parseGenesisAlloc({
+      "0x0000000000000000000000000000000000000001": { balance: "0x1" },
+    });
+
+    assert.lengthOf(alloc, 1);
+    assert.equal(alloc[0].balance, "0x1");
+  });
+
+  it("throws on invalid accounts", async () => {
+    assert.throws(
+      () => parseGenesisAlloc({ alloc: { "0x1234": { balance: "1" } } }),
+      "Invalid address 0x1234"
+    );
+    assert.throws(
+      () =>
+        parseGenesisAlloc({
+          alloc: {
+            "0x0000000000000000000000000000000000000001": { balance: "-1" },
+          },
+        }),
+      "Invalid balance of 0x0000000000000000000000000000000000000001"
+    );
+    assert.throws(
+      () =>
+        parseGenesisAlloc({
+          alloc: {
+            "0x0000000000000000000000000000000000000001": { code: "0x123" },
+          },
+        }),
+      "Invalid code of 0x0000000000000000000000000000000000000001"
+    );
+  });
+});
@@ -0,0 +1,81 @@
+import { assert } from "chai";
+
+import { HARDHAT_NETWORK_MNEMONIC } from "../../../../../src/internal/core/config/default-config";
+import {
+  getHardhatNetworkAccountsDerivationPaths,
+  normalizeHardhatNetworkAccountsConfig,
+} from "../../../../../src/internal/core/providers/util";
+import { getAccountsInfo } from "../../../../../src/internal/hardhat-network/provider/utils/getAccountsInfo";
+import { HardhatNetworkHDAccountsConfig } from "../../../../../src/types";
+
+const PUBLIC_KEY_REGEX = /^0x04[a-f\d]{128}$/;
+
+describe("getAccountsInfo", () => {
+  const accountsConfig: HardhatNetworkHDAccountsConfig = {
+    mnemonic: HARDHAT_NETWORK_MNEMONIC,
+    path: "m/44'/60'/0'/0",
+    initialIndex: 1,
+    count: 2,
+    accountsBalance: "10000000000000000000000",
+    passphrase: "",
+  };
+
+  const genesisAccounts = () => {
+    const derivationPaths =
+      getHardhatNetworkAccountsDerivationPaths(accountsConfig);
+
+    return normalizeHardhatNetworkAccountsConfig(accountsConfig).map(
+      (account, i) => ({ ...account, derivationPath: derivationPaths?.[i] })
+    );
+  };
+
+  it("returns the address, public key and derivation path", async () => {
+    const accountsInfo = getAccountsInfo(genesisAccounts(), false);
+
+    assert.deepEqual(
+      accountsInfo.map((accountInfo) => accountInfo.address),
+      [
+        "0x70997970c51812dc3a010c7d01b50e0d17dc79c8",
+        "0x3c44cdddb6a900fa2b585dd299e03d12fa4293bc",
+      ]
+    );
+    assert.deepEqual(
+      accountsInfo.map((accountInfo) => accountInfo.derivationPath),
+      ["m/44'/60'/0'/0/1", "m/44'/60'/0'/0/2"]
+    );
+
+    for (const accountInfo of accountsInfo) {
+      assert.match(accountInfo.publicKey, PUBLIC_KEY_REGEX);
+      assert.isUndefined(accountInfo.privateKey);
+    }
+  });
+
+  it("only returns the private keys if they are exposed", async () => {
+    const accounts = genesisAccounts();
+    const accountsInfo = getAccountsInfo(accounts, true);
+
+    assert.deepEqual(
+      accountsInfo.map((accountInfo) => accountInfo.privateKey),
+      accounts.map((account) => account.privateKey)
+    );
+  });
+
+  it("doesn't return derivation paths for accounts without one", async () => {
+    const [accountInfo] = getAccountsInfo(
+      [
+        {
+          privateKey:
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+          balance: "0",
+        },
+      ],
+      false
+    );
+
+    assert.equal(
+      accountInfo.address,
+      "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"
+    );
+    assert.notProperty(accountInfo, "derivationPath");
+  });
+});
@@ -0,0 +1,91 @@
+import { assert } from "chai";
+
+import {
+  LimitExceededError,
+  ResourceNotFoundError,
+} from "../../../../../src/internal/core/providers/errors";
+import {
+  hasHistoricalState,
+  isMissingStateError,
+  missingForkStateError,
+} from "../../../../../src/internal/hardhat-network/provider/utils/probeForkCapabilities";
+import { MockedProvider } from "../../../core/providers/mocks";
+
+describe("hasHistoricalState", function () {
+  let forkProvider: MockedProvider;
+
+  beforeEach(function () {
+    forkProvider = new MockedProvider();
+  });
+
+  it("should return true if the node has the state of the block", async function () {
+    forkProvider.setReturnValue("eth_getBalance", "0x0");
+
+    assert.isTrue(await hasHistoricalState(forkProvider, 100));
+    assert.deepEqual(forkProvider.getLatestParams("eth_getBalance"), [
+      "0x0000000000000000000000000000000000000000",
+      "0x64",
+    ]);
+  });
+
+  for (const message of [
+    "missing trie node 1234abcd (path ) state 0x1234 is not available",
+    "header not found",
+    "historical state d7f8974f is not available",
+  ]) {
+    it(`should return false if the node returns '${message}'`, async function () {
+      forkProvider.setReturnValue("eth_getBalance", () => {
+        throw new ResourceNotFoundError(message);
+      });
+
+      assert.isFalse(await hasHistoricalState(forkProvider, 100));
+    });
+  }
+
+  it("should throw other errors returned by the node", async function () {
+    forkProvider.setReturnValue("eth_getBalance", () => {
+      throw new LimitExceededError("Too Many Requests");
+    });
+
+    await assert.isRejected(
+      hasHistoricalState(forkProvider, 100),
+      "Too Many Requests"
+    );
+  });
+
+  it("should throw network errors", async function () {
+    forkProvider.setReturnValue("eth_getBalance", () => {
+      throw new Error("getaddrinfo ENOTFOUND example.com");
+    });
+
+    await assert.isRejected(
+      hasHistoricalState(forkProvider, 100),
+      "getaddrinfo ENOTFOUND"
+    );
+  });
+});
+
+describe("isMissingStateError", function () {
+  it("should only recognize the errors of nodes without the state", function () {
+    assert.isTrue(
+      isMissingStateError(new ResourceNotFoundError("header not found"))
+    );
+    assert.isFalse(
+      isMissingStateError(new LimitExceededError("Too Many Requests"))
+    );
+    assert.isFalse(isMissingStateError(new Error("header not found")));
+  });
+});
+
+describe("missingForkStateError", function () {
+  it("should name the fork block and the archive node requirement", function () {
+    const parent = new ResourceNotFoundError("header not found");
+    const error = missingForkStateError(100, parent);
+
+    assert.include(error.message, "fork from block 100");
+    assert.include(error.message, "requires an archive node");
+    assert.include(error.message, "header not found");
+    assert.equal(error.code, ResourceNotFoundError.CODE);
+    assert.strictEqual(error.parent, parent);
+  });
+});
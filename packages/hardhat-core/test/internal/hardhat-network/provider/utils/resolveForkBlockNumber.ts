@@ -0,0 +1,116 @@
+import { assert } from "chai";
+
+import { InvalidArgumentsError } from "../../../../../src/internal/core/providers/errors";
+import { resolveForkBlockNumber } from "../../../../../src/internal/hardhat-network/provider/utils/resolveForkBlockNumber";
+import { MockedProvider } from "../../../core/providers/mocks";
+
+describe("resolveForkBlockNumber", function () {
+  const jsonRpcUrl = "http://example.com";
+  const blockHash = `0x${"ab".repeat(32)}`;
+  const otherBlockHash = `0x${"cd".repeat(32)}`;
+
+  let forkProvider: MockedProvider;
+
+  beforeEach(function () {
+    forkProvider = new MockedProvider();
+  });
+
+  it("should return configs with a block number as they are", async function () {
+    const forkConfig = { jsonRpcUrl, blockNumber: 100 };
+
+    assert.strictEqual(
+      await resolveForkBlockNumber(forkConfig, forkProvider),
+      forkConfig
+    );
+    assert.equal(forkProvider.getTotalNumberOfCalls(), 0);
+  });
+
+  describe("block tags", function () {
+    it("should resolve a block tag to the number of its block", async function () {
+      forkProvider.setReturnValue("eth_getBlockByNumber", {
+        number: "0x64",
+        hash: blockHash,
+      });
+
+      assert.deepEqual(
+        await resolveForkBlockNumber(
+          { jsonRpcUrl, blockTag: "finalized" },
+          forkProvider
+        ),
+        { jsonRpcUrl, blockNumber: 100 }
+      );
+      assert.deepEqual(forkProvider.getLatestParams("eth_getBlockByNumber"), [
+        "finalized",
+        false,
+      ]);
+    });
+
+    it("should reject tags that the remote node doesn't support", async function () {
+      forkProvider.setReturnValue("eth_getBlockByNumber", () => {
+        throw new InvalidArgumentsError("invalid block tag");
+      });
+
+      await assert.isRejected(
+        resolveForkBlockNumber({ jsonRpcUrl, blockTag: "safe" }, forkProvider),
+        "doesn't support that block tag"
+      );
+    });
+
+    it("should reject tags without a block", async function () {
+      forkProvider.setReturnValue("eth_getBlockByNumber", null);
+
+      await assert.isRejected(
+        resolveForkBlockNumber({ jsonRpcUrl, blockTag: "safe" }, forkProvider),
+        "doesn't have one yet"
+      );
+    });
+  });
+
+  describe("block hashes", function () {
+    it("should resolve a canonical block hash to the number of its block", async function () {
+      forkProvider.setReturnValue("eth_getBlockByHash", {
+        number: "0x64",
+        hash: blockHash,
+      });
+      forkProvider.setReturnValue("eth_getBlockByNumber", {
+        number: "0x64",
+        hash: blockHash,
+      });
+
+      assert.deepEqual(
+        await resolveForkBlockNumber({ jsonRpcUrl, blockHash }, forkProvider),
+        { jsonRpcUrl, blockNumber: 100 }
+      );
+      assert.deepEqual(forkProvider.getLatestParams("eth_getBlockByNumber"), [
+        "0x64",
+        false,
+      ]);
+    });
+
+    it("should reject a block hash that isn't canonical", async function () {
+      forkProvider.setReturnValue("eth_getBlockByHash", {
+        number: "0x64",
+        hash: blockHash,
+      });
+      forkProvider.setReturnValue("eth_getBlockByNumber", {
+        number: "0x64",
+        hash: otherBlockHash,
+      });
+
+      await assert.isRejected(
+        resolveForkBlockNumber({ jsonRpcUrl, blockHash }, forkProvider),
+        "isn't part of the remote node's canonical chain"
+      );
+    });
+
+    it("should reject an unknown block hash", async function () {
+      forkProvider.setReturnValue("eth_getBlockByHash", null);
+
+      await assert.isRejected(
+        resolveForkBlockNumber({ jsonRpcUrl, blockHash }, forkProvider),
+        "doesn't have a block with that hash"
+      );
+      assert.equal(forkProvider.getNumberOfCalls("eth_getBlockByNumber"), 0);
+    });
+  });
+});
@@ -0,0 +1,74 @@
+import { assert } from "chai";
+
+import { EdrProviderWrapper } from "../../../../src/internal/hardhat-network/provider/provider";
+import { PROVIDERS } from "../helpers/providers";
+import { sleep } from "../helpers/sleep";
+
+describe("Hardhat Network provider", function () {
+  PROVIDERS.forEach(({ name, useProvider, isFork }) => {
+    if (isFork) {
+      return;
+    }
+
+    describe(`${name} provider`, function () {
+      describe("hardhat_waitForFilterChanges", function () {
+        useProvider();
+
+        it("should return the changes once they arrive", async function () {
+          const filterId = await this.provider.send("eth_newBlockFilter");
+
+          const changesPromise = this.provider.send(
+            "hardhat_waitForFilterChanges",
+            [filterId, 10_000]
+          );
+
+          await sleep(200);
+          await this.provider.send("evm_mine");
+
+          const block = await this.provider.send("eth_getBlockByNumber", [
+            "latest",
+            false,
+          ]);
+          assert.deepEqual(await changesPromise, [block.hash]);
+        });
+
+        it("should return an empty array if the timeout expires", async function () {
+          const filterId = await this.provider.send("eth_newBlockFilter");
+
+          const start = Date.now();
+          const changes = await this.provider.send(
+            "hardhat_waitForFilterChanges",
+            [filterId, 300]
+          );
+
+          assert.deepEqual(changes, []);
+          assert.isAtLeast(Date.now() - start, 300);
+        });
+
+        it("should return null if the filter doesn't exist", async function () {
+          assert.isNull(
+            await this.provider.send("hardhat_waitForFilterChanges", ["0x123"])
+          );
+        });
+
+        it("should stop waiting when the waits are cancelled", async function () {
+          const filterId = await this.provider.send("eth_newBlockFilter");
+
+          const start = Date.now();
+          const changesPromise = this.provider.send(
+            "hardhat_waitForFilterChanges",
+            [filterId, 10_000]
+          );
+
+          await sleep(200);
+          (
+            this.hardhatNetworkProvider as EdrProviderWrapper
+          ).cancelFilterChangeWaits();
+
+          assert.deepEqual(await changesPromise, []);
+          assert.isBelow(Date.now() - start, 5_000);
+        });
+      });
+    });
+  });
+});
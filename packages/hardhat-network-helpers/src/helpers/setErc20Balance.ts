@@ -0,0 +1,114 @@
+import type EthereumJSUtil from "ethereumjs-util";
+import type { EIP1193Provider } from "hardhat/types";
+import type { NumberLike } from "../types";
+import { HardhatNetworkHelpersError } from "../errors";
+import {
+  getHardhatProvider,
+  assertValidAddress,
+  toPaddedRpcQuantity,
+  toRpcQuantity,
+} from "../utils";
+
+const BALANCE_OF_SELECTOR = "0x70a08231";
+
+// The number of storage slots where the balances mapping is looked for
+const MAX_BALANCES_SLOT = 100;
+
+// A value that balanceOf is very unlikely to return by chance
+const PROBE_VALUE =
+  "0x00000000000000000000000000000000000000000000000000000000deadbeef";
+
+/**
+ * Sets the ERC-20 token balance of an account.
+ *
+ * The storage slot of the balance is found by writing a value to each
+ * candidate slot and checking if `balanceOf` returns it. Both Solidity and
+ * Vyper mapping layouts are supported, but tokens that don't keep balances
+ * in a mapping of full storage words (e.g. rebasing tokens) aren't.
+ *
+ * @param token The address of the token, or of its proxy.
+ * @param holder The address whose balance will be edited.
+ * @param amount The new balance, in the token's smallest unit.
+ */
+export async function setErc20Balance(
+  token: string,
+  holder: string,
+  amount: NumberLike
+): Promise<void> {
+  const provider = await getHardhatProvider();
+
+  assertValidAddress(token);
+  assertValidAddress(holder);
+  const amountHex = toPaddedRpcQuantity(amount, 32);
+
+  for (let slot = 0; slot < MAX_BALANCES_SLOT; slot++) {
+    for (const storageKey of getBalanceStorageKeys(holder, slot)) {
+      const originalValue = (await provider.request({
+        method: "eth_getStorageAt",
+        params: [token, storageKey, "latest"],
+      })) as string;
+
+      await setStorage(provider, token, storageKey, PROBE_VALUE);
+
+      const balance = await getBalance(provider, token, holder);
+      if (balance === BigInt(PROBE_VALUE)) {
+        await setStorage(provider, token, storageKey, amountHex);
+        return;
+      }
+
+      await setStorage(provider, token, storageKey, originalValue);
+    }
+  }
+
+  throw new HardhatNetworkHelpersError(
+    `Couldn't find the storage slot of the balance of ${holder} in the token ${token}`
+  );
+}
+
+/**
+ * Returns the storage keys where the balance of `holder` would be if the
+ * balances mapping was at `slot`, for Solidity and Vyper respectively.
+ */
+function getBalanceStorageKeys(holder: string, slot: number): string[] {
+  const { bufferToHex, keccak256, setLengthLeft, toBuffer } =
+    require("ethereumjs-util") as typeof EthereumJSUtil;
+
+  const holderWord = setLengthLeft(toBuffer(holder), 32);
+  const slotWord = setLengthLeft(toBuffer(slot), 32);
+
+  return [
+    bufferToHex(keccak256(Buffer.concat([holderWord, slotWord]))),
+    bufferToHex(keccak256(Buffer.concat([slotWord, holderWord]))),
+  ];
+}
+
+async function getBalance(
+  provider: EIP1193Provider,
+  token: string,
+  holder: string
+): Promise<bigint | undefined> {
+  const data = `${BALANCE_OF_SELECTOR}${holder.slice(2).padStart(64, "0")}`;
+
+  try {
+    const result = (await provider.request({
+      method: "eth_call",
+      params: [{ to: token, data }, "latest"],
+    })) as string;
+
+    return result.length >= 66 ? BigInt(result.slice(0, 66)) : undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+async function setStorage(
+  provider: EIP1193Provider,
+  address: string,
+  storageKey: string,
+  value: string
+) {
+  await provider.request({
+    method: "hardhat_setStorageAt",
+    params: [address, toRpcQuantity(storageKey), value],
+  });
+}
@@ -0,0 +1,212 @@
+import type { EIP1193Provider } from "hardhat/types";
+import type { NumberLike } from "../types";
+import { HardhatNetworkHelpersError } from "../errors";
+import {
+  getHardhatProvider,
+  assertValidAddress,
+  toBigInt,
+  toPaddedRpcQuantity,
+  toRpcQuantity,
+} from "../utils";
+import { latest } from "./time/latest";
+
+/**
+ * The runtime code of a minimal Chainlink-style aggregator. It implements
+ * `decimals()`, `latestRoundData()`, `getRoundData(uint80)`,
+ * `latestAnswer()`, `latestTimestamp()`, `latestRound()`, `version()` and
+ * `description()`, and reads their values from these storage slots:
+ *
+ * - 0: the round id
+ * - 1: the answer
+ * - 2: the timestamp of the answer, used as both `startedAt` and `updatedAt`
+ * - 3: the number of decimals
+ * - 4: the description, encoded like a Solidity short string
+ * - 5: the version
+ *
+ * Only the latest round is kept, so `getRoundData` reverts for any other
+ * round id, like Chainlink feeds do for rounds they don't have.
+ */
+const MOCK_AGGREGATOR_CODE =
+  "0x60003560e01c8063313ce56714605b578063feaf968c1460955780639a6fc8f5146087" +
+  "57806350d25bcd1460625780638205bf6a146069578063668a0f0214607057806354fd4d" +
+  "501460775780637284e4161460b7575b600080fd5b600354607e565b600154607e565b60" +
+  "0254607e565b600054607e565b600554607e565b60005260206000f35b60043560005414" +
+  "6095576056565b6000546000526001546020526002548060405260605260005460805260" +
+  "a06000f35b60206000526004548060ff1660011c60205260ff191660405260606000f3";
+
+const ROUND_ID_SLOT = 0;
+const ANSWER_SLOT = 1;
+const TIMESTAMP_SLOT = 2;
+const DECIMALS_SLOT = 3;
+const DESCRIPTION_SLOT = 4;
+const VERSION_SLOT = 5;
+
+const DECIMALS_SELECTOR = "0x313ce567";
+const LATEST_ROUND_SELECTOR = "0x668a0f02";
+const VERSION_SELECTOR = "0x54fd4d50";
+const DESCRIPTION_SELECTOR = "0x7284e416";
+
+// A short string fits in a single storage slot with its length
+const MAX_DESCRIPTION_LENGTH = 31;
+
+const MIN_INT256 = -(BigInt(2) ** BigInt(255));
+const MAX_INT256 = BigInt(2) ** BigInt(255) - BigInt(1);
+
+// The number of decimals of most Chainlink USD feeds
+const DEFAULT_DECIMALS = BigInt(8);
+
+/**
+ * Sets the answer of a Chainlink-style price feed, so that tests can move
+ * prices without knowing the storage layout of the feed.
+ *
+ * The first time it's called for a feed, its code is replaced with a mock
+ * aggregator that keeps the feed's decimals, latest round id, version and
+ * description, which is truncated to 31 bytes. Each call starts a new round.
+ *
+ * @param feed The address of the price feed, or of its proxy.
+ * @param answer The new answer, which can be negative but has to fit in an `int256`.
+ * @param updatedAt The timestamp of the answer. Defaults to the timestamp of the latest block.
+ */
+export async function setPriceFeedAnswer(
+  feed: string,
+  answer: NumberLike,
+  updatedAt?: NumberLike
+): Promise<void> {
+  const provider = await getHardhatProvider();
+
+  assertValidAddress(feed);
+  const answerWord = toInt256Word(answer);
+
+  const code = (await provider.request({
+    method: "eth_getCode",
+    params: [feed, "latest"],
+  })) as string;
+
+  let roundId: bigint;
+  if (code.toLowerCase() === MOCK_AGGREGATOR_CODE) {
+    roundId = await getStorageSlot(provider, feed, ROUND_ID_SLOT);
+  } else {
+    const decimals =
+      (await callUintGetter(provider, feed, DECIMALS_SELECTOR)) ??
+      DEFAULT_DECIMALS;
+    roundId =
+      (await callUintGetter(provider, feed, LATEST_ROUND_SELECTOR)) ??
+      BigInt(0);
+    const version =
+      (await callUintGetter(provider, feed, VERSION_SELECTOR)) ?? BigInt(0);
+    const description =
+      (await callStringGetter(provider, feed, DESCRIPTION_SELECTOR)) ?? "";
+
+    await provider.request({
+      method: "hardhat_setCode",
+      params: [feed, MOCK_AGGREGATOR_CODE],
+    });
+    await setStorageSlot(provider, feed, DECIMALS_SLOT, decimals);
+    await setStorageSlot(
+      provider,
+      feed,
+      DESCRIPTION_SLOT,
+      toShortStringWord(description)
+    );
+    await setStorageSlot(provider, feed, VERSION_SLOT, version);
+  }
+
+  const timestamp =
+    updatedAt !== undefined ? toBigInt(updatedAt) : BigInt(await latest());
+
+  await setStorageSlot(provider, feed, ROUND_ID_SLOT, roundId + BigInt(1));
+  await setStorageSlot(provider, feed, ANSWER_SLOT, answerWord);
+  await setStorageSlot(provider, feed, TIMESTAMP_SLOT, timestamp);
+}
+
+function toInt256Word(x: NumberLike): bigint {
+  const value =
+    typeof x === "number" || typeof x === "bigint" ? BigInt(x) : toBigInt(x);
+
+  if (value < MIN_INT256 || value > MAX_INT256) {
+    throw new HardhatNetworkHelpersError(
+      `Invalid answer ${value}: price feed answers have to fit in an int256`
+    );
+  }
+
+  return value < BigInt(0) ? value + BigInt(2) ** BigInt(256) : value;
+}
+
+/**
+ * Encodes a string like Solidity stores short strings: its bytes are left
+ * aligned, and the lowest byte has twice its length.
+ */
+function toShortStringWord(value: string): bigint {
+  const bytes = Buffer.from(value, "utf8").subarray(0, MAX_DESCRIPTION_LENGTH);
+  const hex = bytes.toString("hex").padEnd(2 * MAX_DESCRIPTION_LENGTH, "0");
+  const length = (2 * bytes.length).toString(16).padStart(2, "0");
+
+  return BigInt(`0x${hex}${length}`);
+}
+
+async function callUintGetter(
+  provider: EIP1193Provider,
+  address: string,
+  selector: string
+): Promise<bigint | undefined> {
+  try {
+    const result = (await provider.request({
+      method: "eth_call",
+      params: [{ to: address, data: selector }, "latest"],
+    })) as string;
+
+    return result.length >= 66 ? BigInt(result.slice(0, 66)) : undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+async function callStringGetter(
+  provider: EIP1193Provider,
+  address: string,
+  selector: string
+): Promise<string | undefined> {
+  try {
+    const result = (await provider.request({
+      method: "eth_call",
+      params: [{ to: address, data: selector }, "latest"],
+    })) as string;
+
+    // an ABI-encoded string: its offset, its length and its bytes
+    const data = Buffer.from(result.slice(2), "hex");
+    const offset = Number(BigInt(`0x${data.subarray(0, 32).toString("hex")}`));
+    const length = Number(
+      BigInt(`0x${data.subarray(offset, offset + 32).toString("hex")}`)
+    );
+    const bytes = data.subarray(offset + 32, offset + 32 + length);
+
+    return bytes.length === length ? bytes.toString("utf8") : undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+async function getStorageSlot(
+  provider: EIP1193Provider,
+  address: string,
+  slot: number
+): Promise<bigint> {
+  const value = (await provider.request({
+    method: "eth_getStorageAt",
+    params: [address, toPaddedRpcQuantity(slot, 32), "latest"],
+  })) as string;
+
+  return BigInt(value);
+}
+
+async function setStorageSlot(
+  provider: EIP1193Provider,
+  address: string,
+  slot: number,
+  value: bigint
+) {
+  await provider.request({
+    method: "hardhat_setStorageAt",
+    params: [address, toRpcQuantity(slot), toPaddedRpcQuantity(value, 32)],
+  });
+}
@@ -10,8 +10,10 @@ export { setBalance } from "./helpers/setBalance";
 export { setBlockGasLimit } from "./helpers/setBlockGasLimit";
 export { setCode } from "./helpers/setCode";
 export { setCoinbase } from "./helpers/setCoinbase";
+export { setErc20Balance } from "./helpers/setErc20Balance";
 export { setNonce } from "./helpers/setNonce";
 export { setPrevRandao } from "./helpers/setPrevRandao";
+export { setPriceFeedAnswer } from "./helpers/setPriceFeedAnswer";
 export { setStorageAt } from "./helpers/setStorageAt";
 export { setNextBlockBaseFeePerGas } from "./helpers/setNextBlockBaseFeePerGas";
 export { stopImpersonatingAccount } from "./helpers/stopImpersonatingAccount";
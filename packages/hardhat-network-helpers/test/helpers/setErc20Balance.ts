@@ -0,0 +1,60 @@
+import { assert } from "chai";
+
+import * as hh from "../../src";
+import { useEnvironment } from "../test-utils";
+
+describe("setErc20Balance", function () {
+  useEnvironment("simple");
+  const token = "0x000000000000000000000000000000000000bEEF";
+  const holder = "0x000000000000000000000000000000000000BEEe";
+
+  // balanceOf(holder) of a token with a Solidity balances mapping at slot 2
+  const solidityToken = "0x600435600052600260205260406000205460005260206000f3";
+  // balanceOf(holder) of a token with a Vyper balances mapping at slot 3
+  const vyperToken = "0x600360005260043560205260406000205460005260206000f3";
+
+  const balanceOf = async (): Promise<bigint> => {
+    const result: string = await this.ctx.hre.network.provider.send(
+      "eth_call",
+      [
+        {
+          to: token,
+          data: `0x70a08231${holder.slice(2).padStart(64, "0")}`,
+        },
+      ]
+    );
+
+    return BigInt(result);
+  };
+
+  it("should set the balance of a token with a Solidity layout", async function () {
+    await hh.setCode(token, solidityToken);
+
+    await hh.setErc20Balance(token, holder, 1000);
+
+    assert.equal(await balanceOf(), BigInt(1000));
+  });
+
+  it("should set the balance of a token with a Vyper layout", async function () {
+    await hh.setCode(token, vyperToken);
+
+    await hh.setErc20Balance(token, holder, 1000);
+
+    assert.equal(await balanceOf(), BigInt(1000));
+  });
+
+  it("should throw if the balance can't be found", async function () {
+    // returns 18 for every call
+    await hh.setCode(token, "0x601260005260206000f3");
+
+    await assert.isRejected(
+      hh.setErc20Balance(token, holder, 1000),
+      "Couldn't find the storage slot of the balance"
+    );
+  });
+
+  it("should not accept invalid addresses", async function () {
+    await assert.isRejected(hh.setErc20Balance("0xa", holder, 1000));
+    await assert.isRejected(hh.setErc20Balance(token, "0xa", 1000));
+  });
+});
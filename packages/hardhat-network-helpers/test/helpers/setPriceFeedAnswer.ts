@@ -0,0 +1,144 @@
+import { assert } from "chai";
+
+import * as hh from "../../src";
+import { HardhatNetworkHelpersError } from "../../src/errors";
+import { useEnvironment } from "../test-utils";
+
+describe("setPriceFeedAnswer", function () {
+  useEnvironment("simple");
+  const feed = "0x000000000000000000000000000000000000bEEF";
+
+  const call = async (selector: string): Promise<bigint[]> => {
+    const result: string = await this.ctx.hre.network.provider.send(
+      "eth_call",
+      [{ to: feed, data: selector }]
+    );
+
+    const words = result.slice(2).match(/.{64}/g) ?? [];
+    return words.map((word) => BigInt(`0x${word}`));
+  };
+
+  const latestRoundData = () => call("0xfeaf968c");
+  const getRoundData = (roundId: number) =>
+    call(`0x9a6fc8f5${roundId.toString(16).padStart(64, "0")}`);
+  const decimals = async () => (await call("0x313ce567"))[0];
+  const version = async () => (await call("0x54fd4d50"))[0];
+  const description = async () => {
+    const [, length, data] = await call("0x7284e416");
+    return Buffer.from(data.toString(16).padStart(64, "0"), "hex")
+      .subarray(0, Number(length))
+      .toString("utf8");
+  };
+
+  it("should install a mock aggregator with the given answer", async function () {
+    await hh.setPriceFeedAnswer(feed, 2000, 1700000000);
+
+    const [roundId, answer, startedAt, updatedAt, answeredInRound] =
+      await latestRoundData();
+
+    assert.equal(roundId, BigInt(1));
+    assert.equal(answer, BigInt(2000));
+    assert.equal(startedAt, BigInt(1700000000));
+    assert.equal(updatedAt, BigInt(1700000000));
+    assert.equal(answeredInRound, BigInt(1));
+    assert.equal(await decimals(), BigInt(8));
+  });
+
+  it("should start a new round on each call", async function () {
+    await hh.setPriceFeedAnswer(feed, 2000);
+    await hh.setPriceFeedAnswer(feed, 2100);
+
+    const [roundId, answer] = await latestRoundData();
+
+    assert.equal(roundId, BigInt(2));
+    assert.equal(answer, BigInt(2100));
+  });
+
+  it("should use the latest block's timestamp by default", async function () {
+    await hh.setPriceFeedAnswer(feed, 2000);
+
+    const [, , , updatedAt] = await latestRoundData();
+
+    assert.equal(updatedAt, BigInt(await hh.time.latest()));
+  });
+
+  it("should accept negative answers", async function () {
+    await hh.setPriceFeedAnswer(feed, -5);
+
+    const [, answer] = await latestRoundData();
+
+    assert.equal(BigInt.asIntN(256, answer), BigInt(-5));
+  });
+
+  it("should keep the decimals and round id of an existing feed", async function () {
+    // returns 18 for every call
+    await hh.setCode(feed, "0x601260005260206000f3");
+
+    await hh.setPriceFeedAnswer(feed, 2000);
+
+    const [roundId] = await latestRoundData();
+
+    assert.equal(roundId, BigInt(19));
+    assert.equal(await decimals(), BigInt(18));
+  });
+
+  it("should return the latest round from getRoundData", async function () {
+    await hh.setPriceFeedAnswer(feed, 2000, 1700000000);
+    await hh.setPriceFeedAnswer(feed, 2100, 1700000100);
+
+    assert.deepEqual(await getRoundData(2), await latestRoundData());
+    await assert.isRejected(getRoundData(1));
+    await assert.isRejected(getRoundData(3));
+  });
+
+  it("should use the default version and description for new feeds", async function () {
+    await hh.setPriceFeedAnswer(feed, 2000);
+
+    assert.equal(await version(), BigInt(0));
+    assert.equal(await description(), "");
+  });
+
+  it("should keep the version of an existing feed", async function () {
+    // returns 4 for every call
+    await hh.setCode(feed, "0x600460005260206000f3");
+
+    await hh.setPriceFeedAnswer(feed, 2000);
+
+    assert.equal(await version(), BigInt(4));
+  });
+
+  it("should keep the description of an existing feed", async function () {
+    // returns "ETH / USD" for every call
+    await hh.setCode(
+      feed,
+      "0x602060005260096020527f455448202f20555344000000000000000000000000" +
+        "000000000000000000000060405260606000f3"
+    );
+
+    await hh.setPriceFeedAnswer(feed, 2000);
+
+    assert.equal(await description(), "ETH / USD");
+  });
+
+  it("should accept answers at the limits of int256", async function () {
+    const max = BigInt(2) ** BigInt(255) - BigInt(1);
+    const min = -(BigInt(2) ** BigInt(255));
+
+    await hh.setPriceFeedAnswer(feed, max);
+    assert.equal((await latestRoundData())[1], max);
+
+    await hh.setPriceFeedAnswer(feed, min);
+    assert.equal(BigInt.asIntN(256, (await latestRoundData())[1]), min);
+  });
+
+  it("should reject answers that don't fit in an int256", async function () {
+    await assert.isRejected(
+      hh.setPriceFeedAnswer(feed, BigInt(2) ** BigInt(255)),
+      HardhatNetworkHelpersError
+    );
+    await assert.isRejected(
+      hh.setPriceFeedAnswer(feed, -(BigInt(2) ** BigInt(255)) - BigInt(1)),
+      HardhatNetworkHelpersError
+    );
+  });
+});